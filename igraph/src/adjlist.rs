@@ -0,0 +1,299 @@
+use std::cell::UnsafeCell;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+
+use igraph_sys::*;
+
+use crate::error::{Error, Result, check};
+use crate::graph::Graph;
+use crate::types::{Loops, NeighborMode};
+use crate::vector::vector_int_slice;
+
+/// Snapshot of the neighbors of every vertex, wrapping `igraph_adjlist_t`.
+///
+/// Neighbor lists are exposed as slices without copying, which makes this
+/// much cheaper than repeated [`Graph::neighbors`] calls.
+pub struct AdjList<'a> {
+    inner: igraph_adjlist_t,
+    _graph: PhantomData<&'a Graph>,
+}
+
+impl<'a> AdjList<'a> {
+    /// Build the adjacency list of `graph`.
+    ///
+    /// `loops` controls how often self-loops are listed and `multiple`
+    /// whether multi-edges give repeated neighbors.
+    pub fn new(graph: &'a Graph, mode: NeighborMode, loops: Loops, multiple: bool) -> Result<Self> {
+        Self::init_with(|al| unsafe {
+            igraph_adjlist_init(graph.as_ptr(), al, mode.to_raw(), loops.to_raw(), multiple)
+        })
+    }
+
+    /// Build the adjacency list of the complement of `graph`, listing for
+    /// each vertex the vertices it is *not* connected to.
+    pub fn complementer(graph: &'a Graph, mode: NeighborMode, loops: Loops) -> Result<Self> {
+        Self::init_with(|al| unsafe {
+            igraph_adjlist_init_complementer(graph.as_ptr(), al, mode.to_raw(), loops.to_raw())
+        })
+    }
+
+    /// Build the adjacency list corresponding to an incidence list, using
+    /// the graph the incidence list was built from.
+    pub fn from_inclist(il: &IncList<'a>) -> Result<Self> {
+        Self::init_with(|al| unsafe {
+            igraph_adjlist_init_from_inclist(il.graph.as_ptr(), al, &il.inner)
+        })
+    }
+
+    fn init_with(f: impl FnOnce(*mut igraph_adjlist_t) -> igraph_error_t) -> Result<Self> {
+        let mut al = MaybeUninit::uninit();
+        check(f(al.as_mut_ptr()))?;
+        Ok(Self {
+            inner: unsafe { al.assume_init() },
+            _graph: PhantomData,
+        })
+    }
+
+    /// Return the number of vertices.
+    pub fn len(&self) -> i64 {
+        self.inner.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the neighbors of vertex `vid`, or `None` if it is out of range.
+    pub fn get(&self, vid: i64) -> Option<&[i64]> {
+        (0..self.len())
+            .contains(&vid)
+            .then(|| unsafe { vector_int_slice(self.inner.adjs.add(vid as usize)) })
+    }
+
+    /// Iterate over the neighbor lists of all vertices in id order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &[i64]> + '_ {
+        (0..self.len() as usize).map(|v| unsafe { vector_int_slice(self.inner.adjs.add(v)) })
+    }
+
+    /// Sort every neighbor list in increasing order.
+    pub fn sort(&mut self) {
+        unsafe { igraph_adjlist_sort(&mut self.inner) }
+    }
+
+    /// Remove self-loops and repeated neighbors. The lists end up sorted.
+    pub fn simplify(&mut self) -> Result<()> {
+        unsafe { check(igraph_adjlist_simplify(&mut self.inner)) }
+    }
+
+    /// Build a graph from the adjacency list.
+    ///
+    /// `NeighborMode::Out` and `NeighborMode::In` create a directed graph
+    /// reading the lists as out- or in-neighbors; `NeighborMode::All`
+    /// creates an undirected one. With `duplicate`, undirected edges are
+    /// expected to appear in the lists of both endpoints and are only
+    /// added once.
+    pub fn to_graph(&self, mode: NeighborMode, duplicate: bool) -> Result<Graph> {
+        Graph::init_with(|g| unsafe { igraph_adjlist(g, &self.inner, mode.to_raw(), duplicate) })
+    }
+}
+
+impl std::ops::Index<i64> for AdjList<'_> {
+    type Output = [i64];
+
+    fn index(&self, vid: i64) -> &[i64] {
+        self.get(vid).expect("vertex id out of range")
+    }
+}
+
+impl Drop for AdjList<'_> {
+    fn drop(&mut self) {
+        unsafe { igraph_adjlist_destroy(&mut self.inner) }
+    }
+}
+
+/// Snapshot of the incident edges of every vertex, wrapping
+/// `igraph_inclist_t`.
+pub struct IncList<'a> {
+    inner: igraph_inclist_t,
+    graph: &'a Graph,
+}
+
+impl<'a> IncList<'a> {
+    /// Build the incidence list of `graph`. `loops` controls how often
+    /// self-loops are listed.
+    pub fn new(graph: &'a Graph, mode: NeighborMode, loops: Loops) -> Result<Self> {
+        let mut il = MaybeUninit::uninit();
+        unsafe {
+            check(igraph_inclist_init(
+                graph.as_ptr(),
+                il.as_mut_ptr(),
+                mode.to_raw(),
+                loops.to_raw(),
+            ))?;
+            Ok(Self {
+                inner: il.assume_init(),
+                graph,
+            })
+        }
+    }
+
+    /// Return the number of vertices.
+    pub fn len(&self) -> i64 {
+        self.inner.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the incident edges of vertex `vid`, or `None` if it is out of
+    /// range.
+    pub fn get(&self, vid: i64) -> Option<&[i64]> {
+        (0..self.len())
+            .contains(&vid)
+            .then(|| unsafe { vector_int_slice(self.inner.incs.add(vid as usize)) })
+    }
+
+    /// Iterate over the incident edge lists of all vertices in id order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &[i64]> + '_ {
+        (0..self.len() as usize).map(|v| unsafe { vector_int_slice(self.inner.incs.add(v)) })
+    }
+}
+
+impl std::ops::Index<i64> for IncList<'_> {
+    type Output = [i64];
+
+    fn index(&self, vid: i64) -> &[i64] {
+        self.get(vid).expect("vertex id out of range")
+    }
+}
+
+impl Drop for IncList<'_> {
+    fn drop(&mut self) {
+        unsafe { igraph_inclist_destroy(&mut self.inner) }
+    }
+}
+
+/// Adjacency list that computes each vertex's neighbors on first access,
+/// wrapping `igraph_lazy_adjlist_t`.
+pub struct LazyAdjList<'a> {
+    // Filled in behind shared references; a list, once computed, is never
+    // changed or freed before the whole structure is dropped.
+    inner: UnsafeCell<igraph_lazy_adjlist_t>,
+    _graph: PhantomData<&'a Graph>,
+}
+
+impl<'a> LazyAdjList<'a> {
+    /// Prepare a lazy adjacency list of `graph`; see [`AdjList::new`] for
+    /// the meaning of `loops` and `multiple`.
+    pub fn new(graph: &'a Graph, mode: NeighborMode, loops: Loops, multiple: bool) -> Result<Self> {
+        let mut al = MaybeUninit::uninit();
+        unsafe {
+            check(igraph_lazy_adjlist_init(
+                graph.as_ptr(),
+                al.as_mut_ptr(),
+                mode.to_raw(),
+                loops.to_raw(),
+                multiple,
+            ))?;
+            Ok(Self {
+                inner: UnsafeCell::new(al.assume_init()),
+                _graph: PhantomData,
+            })
+        }
+    }
+
+    /// Return the number of vertices.
+    pub fn len(&self) -> i64 {
+        unsafe { (*self.inner.get()).length }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the neighbors of vertex `vid`, computing them if needed.
+    pub fn get(&self, vid: i64) -> Result<&[i64]> {
+        if !(0..self.len()).contains(&vid) {
+            return Err(Error::InvalidVertexId);
+        }
+        unsafe {
+            let al = self.inner.get();
+            let mut v = *(*al).adjs.add(vid as usize);
+            if v.is_null() {
+                v = igraph_i_lazy_adjlist_get_real(al, vid);
+                if v.is_null() {
+                    return Err(Error::NoMemory);
+                }
+            }
+            Ok(vector_int_slice(v))
+        }
+    }
+}
+
+impl Drop for LazyAdjList<'_> {
+    fn drop(&mut self) {
+        unsafe { igraph_lazy_adjlist_destroy(self.inner.get_mut()) }
+    }
+}
+
+/// Incidence list that computes each vertex's incident edges on first
+/// access, wrapping `igraph_lazy_inclist_t`.
+pub struct LazyIncList<'a> {
+    // See `LazyAdjList::inner`.
+    inner: UnsafeCell<igraph_lazy_inclist_t>,
+    _graph: PhantomData<&'a Graph>,
+}
+
+impl<'a> LazyIncList<'a> {
+    /// Prepare a lazy incidence list of `graph`. `loops` controls how often
+    /// self-loops are listed.
+    pub fn new(graph: &'a Graph, mode: NeighborMode, loops: Loops) -> Result<Self> {
+        let mut il = MaybeUninit::uninit();
+        unsafe {
+            check(igraph_lazy_inclist_init(
+                graph.as_ptr(),
+                il.as_mut_ptr(),
+                mode.to_raw(),
+                loops.to_raw(),
+            ))?;
+            Ok(Self {
+                inner: UnsafeCell::new(il.assume_init()),
+                _graph: PhantomData,
+            })
+        }
+    }
+
+    /// Return the number of vertices.
+    pub fn len(&self) -> i64 {
+        unsafe { (*self.inner.get()).length }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the incident edges of vertex `vid`, computing them if needed.
+    pub fn get(&self, vid: i64) -> Result<&[i64]> {
+        if !(0..self.len()).contains(&vid) {
+            return Err(Error::InvalidVertexId);
+        }
+        unsafe {
+            let il = self.inner.get();
+            let mut v = *(*il).incs.add(vid as usize);
+            if v.is_null() {
+                v = igraph_i_lazy_inclist_get_real(il, vid);
+                if v.is_null() {
+                    return Err(Error::NoMemory);
+                }
+            }
+            Ok(vector_int_slice(v))
+        }
+    }
+}
+
+impl Drop for LazyIncList<'_> {
+    fn drop(&mut self) {
+        unsafe { igraph_lazy_inclist_destroy(self.inner.get_mut()) }
+    }
+}
@@ -0,0 +1,102 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::ops::ControlFlow;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use igraph_sys::*;
+
+/// Holds a panic raised by a Rust closure invoked from an igraph callback.
+///
+/// Unwinding through C stack frames is undefined behavior, so trampolines run
+/// user closures through [`PanicSlot::call`] (or [`PanicSlot::call_handler`]
+/// for handlers that can stop the search), tell igraph to stop (or answer
+/// conservatively), and the wrapper re-raises the panic via
+/// [`PanicSlot::resume`] once the C function has returned.
+#[derive(Default)]
+pub(crate) struct PanicSlot {
+    payload: Option<Box<dyn Any + Send>>,
+}
+
+impl PanicSlot {
+    /// Run `f`, catching any panic.
+    ///
+    /// Returns `None` if `f` panicked, or if an earlier call already did, in
+    /// which case `f` is not run at all.
+    pub(crate) fn call<R>(&mut self, f: impl FnOnce() -> R) -> Option<R> {
+        if self.payload.is_some() {
+            return None;
+        }
+        match panic::catch_unwind(AssertUnwindSafe(f)) {
+            Ok(res) => Some(res),
+            Err(payload) => {
+                self.payload = Some(payload);
+                None
+            }
+        }
+    }
+
+    /// Run a handler through [`PanicSlot::call`] and translate its answer
+    /// into a return code for igraph: [`ControlFlow::Continue`] carries on,
+    /// while [`ControlFlow::Break`] or a panic stops with `IGRAPH_STOP`.
+    pub(crate) fn call_handler(&mut self, f: impl FnOnce() -> ControlFlow<()>) -> igraph_error_t {
+        match self.call(f) {
+            Some(ControlFlow::Continue(())) => igraph_error_type_t_IGRAPH_SUCCESS,
+            _ => igraph_error_type_t_IGRAPH_STOP,
+        }
+    }
+
+    /// Re-raise the captured panic, if any.
+    pub(crate) fn resume(&mut self) {
+        if let Some(payload) = self.payload.take() {
+            panic::resume_unwind(payload);
+        }
+    }
+}
+
+thread_local! {
+    static DEADLINE: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+unsafe extern "C" fn deadline_interruption_handler() -> igraph_bool_t {
+    DEADLINE.with(|d| d.get().is_some_and(|t| Instant::now() >= t))
+}
+
+/// Interrupts igraph computations on the current thread once a time limit
+/// has passed, making them fail with [`crate::Error::Interrupted`].
+///
+/// igraph's interruption and error handlers are thread-local (we build with
+/// `IGRAPH_ENABLE_TLS=ON`), so the guard only affects calls made on this
+/// thread, and the previous handlers are restored when it is dropped. The
+/// error handler is switched to `igraph_error_handler_ignore` meanwhile so an
+/// interruption is reported as an error code instead of aborting.
+pub(crate) struct DeadlineGuard {
+    prev_deadline: Option<Instant>,
+    prev_interruption: igraph_interruption_handler_t,
+    prev_error: igraph_error_handler_t,
+}
+
+impl DeadlineGuard {
+    pub(crate) fn new(limit: Duration) -> Self {
+        let prev_deadline = DEADLINE.with(|d| d.replace(Some(Instant::now() + limit)));
+        unsafe {
+            Self {
+                prev_deadline,
+                prev_interruption: igraph_set_interruption_handler(Some(
+                    deadline_interruption_handler,
+                )),
+                prev_error: igraph_set_error_handler(Some(igraph_error_handler_ignore)),
+            }
+        }
+    }
+}
+
+impl Drop for DeadlineGuard {
+    fn drop(&mut self) {
+        unsafe {
+            igraph_set_error_handler(self.prev_error);
+            igraph_set_interruption_handler(self.prev_interruption);
+        }
+        DEADLINE.with(|d| d.set(self.prev_deadline));
+    }
+}
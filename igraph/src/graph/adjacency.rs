@@ -0,0 +1,60 @@
+use igraph_sys::*;
+
+use super::Graph;
+use crate::error::{Result, check};
+use crate::matrix::Matrix;
+use crate::types::{AdjacencyType, LaplacianNormalization, Loops, NeighborMode};
+use crate::vector::Vector;
+
+impl Graph {
+    /// Return the adjacency matrix of the graph.
+    ///
+    /// For undirected graphs `kind` selects whether the upper, lower or
+    /// both triangles are filled; it is ignored for directed graphs. With
+    /// `weights`, entries hold the total weight of the edges instead of
+    /// their count. `loops` controls how self-loops are counted on the
+    /// diagonal.
+    pub fn get_adjacency(
+        &self,
+        kind: AdjacencyType,
+        weights: Option<&[f64]>,
+        loops: Loops,
+    ) -> Result<Matrix> {
+        let w = weights.map(Vector::from_slice).transpose()?;
+        let mut res = Matrix::new(0, 0)?;
+        unsafe {
+            check(igraph_get_adjacency(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                kind.to_raw(),
+                w.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                loops.to_raw(),
+            ))?;
+        }
+        Ok(res)
+    }
+
+    /// Return the Laplacian matrix of the graph.
+    ///
+    /// For directed graphs `mode` selects whether out- or in-degrees are
+    /// used; `NeighborMode::All` treats the graph as undirected.
+    pub fn laplacian(
+        &self,
+        mode: NeighborMode,
+        normalization: LaplacianNormalization,
+        weights: Option<&[f64]>,
+    ) -> Result<Matrix> {
+        let w = weights.map(Vector::from_slice).transpose()?;
+        let mut res = Matrix::new(0, 0)?;
+        unsafe {
+            check(igraph_get_laplacian(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                mode.to_raw(),
+                normalization.to_raw(),
+                w.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+            ))?;
+        }
+        Ok(res)
+    }
+}
@@ -0,0 +1,367 @@
+use std::ffi::CString;
+use std::ops::ControlFlow;
+use std::os::raw::c_void;
+use std::path::Path;
+
+use igraph_sys::*;
+
+use super::Graph;
+use crate::callback::PanicSlot;
+use crate::error::{Error, Result, check};
+use crate::vector::{Vector, VectorInt, VectorIntList, vector_int_slice};
+
+const UNLIMITED: i64 = IGRAPH_UNLIMITED as i64;
+
+/// State shared with the clique callback trampoline through igraph's `arg` pointer.
+struct CliqueContext<'a> {
+    handler: &'a mut dyn FnMut(&[i64]) -> ControlFlow<()>,
+    panic: PanicSlot,
+}
+
+unsafe extern "C" fn clique_trampoline(
+    clique: *const igraph_vector_int_t,
+    arg: *mut c_void,
+) -> igraph_error_t {
+    let ctx = unsafe { &mut *arg.cast::<CliqueContext>() };
+    let clique = unsafe { vector_int_slice(clique) };
+    let handler = &mut ctx.handler;
+    ctx.panic.call_handler(|| handler(clique))
+}
+
+impl Graph {
+    /// Find all complete subgraphs with between `min_size` and `max_size`
+    /// vertices.
+    ///
+    /// A bound of 0 (or less) means no bound. Edge directions are ignored.
+    pub fn cliques(&self, min_size: i64, max_size: i64) -> Result<Vec<Vec<i64>>> {
+        let mut res = VectorIntList::new()?;
+        unsafe {
+            check(igraph_cliques(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                min_size,
+                max_size,
+                UNLIMITED, // max_results
+            ))?;
+        }
+        Ok(res.to_vec_of_vecs())
+    }
+
+    /// Stream all cliques with between `min_size` and `max_size` vertices.
+    ///
+    /// `handler` is called with each clique as it is found, without
+    /// collecting them; returning [`ControlFlow::Break`] stops the search.
+    pub fn cliques_callback(
+        &self,
+        min_size: i64,
+        max_size: i64,
+        mut handler: impl FnMut(&[i64]) -> ControlFlow<()>,
+    ) -> Result<()> {
+        let mut ctx = CliqueContext {
+            handler: &mut handler,
+            panic: PanicSlot::default(),
+        };
+        let code = unsafe {
+            igraph_cliques_callback(
+                self.as_ptr(),
+                min_size,
+                max_size,
+                Some(clique_trampoline),
+                (&mut ctx as *mut CliqueContext).cast(),
+            )
+        };
+        ctx.panic.resume();
+        check(code)
+    }
+
+    /// Find all maximal cliques with between `min_size` and `max_size` vertices.
+    ///
+    /// A clique is maximal if it cannot be extended by another vertex. A bound
+    /// of 0 (or less) means no bound.
+    pub fn maximal_cliques(&self, min_size: i64, max_size: i64) -> Result<Vec<Vec<i64>>> {
+        let mut res = VectorIntList::new()?;
+        unsafe {
+            check(igraph_maximal_cliques(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                min_size,
+                max_size,
+                UNLIMITED, // max_results
+            ))?;
+        }
+        Ok(res.to_vec_of_vecs())
+    }
+
+    /// Stream all maximal cliques with between `min_size` and `max_size` vertices.
+    ///
+    /// `handler` is called with each maximal clique as it is found, without
+    /// collecting them; returning [`ControlFlow::Break`] stops the search.
+    pub fn maximal_cliques_callback(
+        &self,
+        min_size: i64,
+        max_size: i64,
+        mut handler: impl FnMut(&[i64]) -> ControlFlow<()>,
+    ) -> Result<()> {
+        let mut ctx = CliqueContext {
+            handler: &mut handler,
+            panic: PanicSlot::default(),
+        };
+        let code = unsafe {
+            igraph_maximal_cliques_callback(
+                self.as_ptr(),
+                min_size,
+                max_size,
+                Some(clique_trampoline),
+                (&mut ctx as *mut CliqueContext).cast(),
+            )
+        };
+        ctx.panic.resume();
+        check(code)
+    }
+
+    /// Write all maximal cliques with between `min_size` and `max_size`
+    /// vertices to the file at `path`, one clique per line.
+    ///
+    /// The file is created or truncated. Vertex ids on a line are separated
+    /// by spaces. Paths that are not valid UTF-8 give `Error::InvalidValue`.
+    pub fn maximal_cliques_file(
+        &self,
+        path: impl AsRef<Path>,
+        min_size: i64,
+        max_size: i64,
+    ) -> Result<()> {
+        let cpath = path
+            .as_ref()
+            .to_str()
+            .and_then(|p| CString::new(p).ok())
+            .ok_or(Error::InvalidValue)?;
+        unsafe {
+            let file = fopen(cpath.as_ptr(), c"w".as_ptr());
+            if file.is_null() {
+                return Err(Error::FileError);
+            }
+            let code = igraph_maximal_cliques_file(
+                self.as_ptr(),
+                file,
+                min_size,
+                max_size,
+                UNLIMITED, // max_results
+            );
+            if fclose(file) != 0 {
+                check(code)?;
+                return Err(Error::FileError);
+            }
+            check(code)
+        }
+    }
+
+    /// Find the maximal cliques whose lowest-ranked vertex is in `subset`.
+    ///
+    /// Running this on disjoint subsets that together cover all vertices
+    /// finds every maximal clique exactly once, which allows splitting the
+    /// work across graph copies.
+    pub fn maximal_cliques_subset(
+        &self,
+        subset: &[i64],
+        min_size: i64,
+        max_size: i64,
+    ) -> Result<Vec<Vec<i64>>> {
+        let subset = VectorInt::from_slice(subset)?;
+        let mut res = VectorIntList::new()?;
+        unsafe {
+            check(igraph_maximal_cliques_subset(
+                self.as_ptr(),
+                subset.as_ptr(),
+                res.as_mut_ptr(),
+                std::ptr::null_mut(), // no
+                std::ptr::null_mut(), // outfile
+                min_size,
+                max_size,
+                UNLIMITED, // max_results
+            ))?;
+        }
+        Ok(res.to_vec_of_vecs())
+    }
+
+    /// Count the maximal cliques with between `min_size` and `max_size` vertices.
+    pub fn maximal_cliques_count(&self, min_size: i64, max_size: i64) -> Result<i64> {
+        let mut res: i64 = 0;
+        unsafe {
+            check(igraph_maximal_cliques_count(
+                self.as_ptr(),
+                &mut res,
+                min_size,
+                max_size,
+            ))?;
+        }
+        Ok(res)
+    }
+
+    /// Count maximal cliques by size.
+    ///
+    /// Element `i` of the result is the number of maximal cliques of size `i + 1`.
+    pub fn maximal_cliques_hist(&self, min_size: i64, max_size: i64) -> Result<Vec<f64>> {
+        let mut hist = Vector::new()?;
+        unsafe {
+            check(igraph_maximal_cliques_hist(
+                self.as_ptr(),
+                hist.as_mut_ptr(),
+                min_size,
+                max_size,
+            ))?;
+        }
+        Ok(hist.to_vec())
+    }
+
+    /// Find all largest cliques, i.e. those of maximum size.
+    pub fn largest_cliques(&self) -> Result<Vec<Vec<i64>>> {
+        let mut res = VectorIntList::new()?;
+        unsafe {
+            check(igraph_largest_cliques(self.as_ptr(), res.as_mut_ptr()))?;
+        }
+        Ok(res.to_vec_of_vecs())
+    }
+
+    /// Return the size of the largest clique.
+    pub fn clique_number(&self) -> Result<i64> {
+        let mut no: i64 = 0;
+        unsafe {
+            check(igraph_clique_number(self.as_ptr(), &mut no))?;
+        }
+        Ok(no)
+    }
+
+    /// Count all cliques by size.
+    ///
+    /// Element `i` of the result is the number of cliques of size `i + 1`.
+    pub fn clique_size_hist(&self, min_size: i64, max_size: i64) -> Result<Vec<f64>> {
+        let mut hist = Vector::new()?;
+        unsafe {
+            check(igraph_clique_size_hist(
+                self.as_ptr(),
+                hist.as_mut_ptr(),
+                min_size,
+                max_size,
+            ))?;
+        }
+        Ok(hist.to_vec())
+    }
+
+    /// Find cliques with total vertex weight between `min_weight` and `max_weight`.
+    ///
+    /// `vertex_weights` must hold one positive weight per vertex. With
+    /// `maximal`, only maximal cliques are returned. A bound of 0 (or less)
+    /// means no bound.
+    pub fn weighted_cliques(
+        &self,
+        vertex_weights: &[f64],
+        maximal: bool,
+        min_weight: f64,
+        max_weight: f64,
+    ) -> Result<Vec<Vec<i64>>> {
+        let weights = Vector::from_slice(vertex_weights)?;
+        let mut res = VectorIntList::new()?;
+        unsafe {
+            check(igraph_weighted_cliques(
+                self.as_ptr(),
+                weights.as_ptr(),
+                res.as_mut_ptr(),
+                maximal,
+                min_weight,
+                max_weight,
+                UNLIMITED, // max_results
+            ))?;
+        }
+        Ok(res.to_vec_of_vecs())
+    }
+
+    /// Find all cliques of maximum total vertex weight.
+    pub fn largest_weighted_cliques(&self, vertex_weights: &[f64]) -> Result<Vec<Vec<i64>>> {
+        let weights = Vector::from_slice(vertex_weights)?;
+        let mut res = VectorIntList::new()?;
+        unsafe {
+            check(igraph_largest_weighted_cliques(
+                self.as_ptr(),
+                weights.as_ptr(),
+                res.as_mut_ptr(),
+            ))?;
+        }
+        Ok(res.to_vec_of_vecs())
+    }
+
+    /// Return the maximum total vertex weight of any clique.
+    pub fn weighted_clique_number(&self, vertex_weights: &[f64]) -> Result<f64> {
+        let weights = Vector::from_slice(vertex_weights)?;
+        let mut res: f64 = 0.0;
+        unsafe {
+            check(igraph_weighted_clique_number(
+                self.as_ptr(),
+                weights.as_ptr(),
+                &mut res,
+            ))?;
+        }
+        Ok(res)
+    }
+
+    /// Find all independent vertex sets with between `min_size` and
+    /// `max_size` vertices.
+    ///
+    /// A bound of 0 (or less) means no bound.
+    pub fn independent_vertex_sets(&self, min_size: i64, max_size: i64) -> Result<Vec<Vec<i64>>> {
+        let mut res = VectorIntList::new()?;
+        unsafe {
+            check(igraph_independent_vertex_sets(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                min_size,
+                max_size,
+                UNLIMITED, // max_results
+            ))?;
+        }
+        Ok(res.to_vec_of_vecs())
+    }
+
+    /// Find all largest independent vertex sets.
+    pub fn largest_independent_vertex_sets(&self) -> Result<Vec<Vec<i64>>> {
+        let mut res = VectorIntList::new()?;
+        unsafe {
+            check(igraph_largest_independent_vertex_sets(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+            ))?;
+        }
+        Ok(res.to_vec_of_vecs())
+    }
+
+    /// Find all maximal independent vertex sets with between `min_size` and
+    /// `max_size` vertices.
+    ///
+    /// A set is maximal if no other vertex can be added to it. A bound of 0
+    /// (or less) means no bound.
+    pub fn maximal_independent_vertex_sets(
+        &self,
+        min_size: i64,
+        max_size: i64,
+    ) -> Result<Vec<Vec<i64>>> {
+        let mut res = VectorIntList::new()?;
+        unsafe {
+            check(igraph_maximal_independent_vertex_sets(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                min_size,
+                max_size,
+                UNLIMITED, // max_results
+            ))?;
+        }
+        Ok(res.to_vec_of_vecs())
+    }
+
+    /// Return the size of the largest independent vertex set.
+    pub fn independence_number(&self) -> Result<i64> {
+        let mut no: i64 = 0;
+        unsafe {
+            check(igraph_independence_number(self.as_ptr(), &mut no))?;
+        }
+        Ok(no)
+    }
+}
@@ -0,0 +1,76 @@
+use igraph_sys::*;
+
+use super::Graph;
+use crate::error::{Error, Result, check};
+use crate::types::NeighborMode;
+use crate::vector::VectorInt;
+
+impl Graph {
+    /// Compute the coreness (shell index) of every vertex.
+    ///
+    /// The k-core is the maximal subgraph in which every vertex has degree
+    /// at least k; a vertex's coreness is the largest k whose k-core contains
+    /// it. `mode` selects in-, out- or total degree in directed graphs.
+    pub fn coreness(&self, mode: NeighborMode) -> Result<Vec<i64>> {
+        let mut cores = VectorInt::new()?;
+        unsafe {
+            check(igraph_coreness(
+                self.as_ptr(),
+                cores.as_mut_ptr(),
+                mode.to_raw(),
+            ))?;
+        }
+        Ok(cores.to_vec())
+    }
+
+    /// Compute the trussness of every edge.
+    ///
+    /// The k-truss is the maximal subgraph in which every edge lies on at
+    /// least k - 2 triangles; an edge's trussness is the largest k whose
+    /// k-truss contains it. Edge directions are ignored and multi-edges are
+    /// not supported.
+    pub fn trussness(&self) -> Result<Vec<i64>> {
+        if self.has_multiple()? {
+            return Err(Error::Unimplemented);
+        }
+        let mut res = VectorInt::new()?;
+        unsafe {
+            check(igraph_trussness(self.as_ptr(), res.as_mut_ptr()))?;
+        }
+        Ok(res.to_vec())
+    }
+
+    /// Extract the k-core as an induced subgraph.
+    ///
+    /// Returns the subgraph and, for each of its vertices, the id of the
+    /// corresponding vertex in this graph.
+    pub fn k_core(&self, k: i64, mode: NeighborMode) -> Result<(Graph, Vec<i64>)> {
+        let vids: Vec<i64> = (0..)
+            .zip(self.coreness(mode)?)
+            .filter(|&(_, c)| c >= k)
+            .map(|(v, _)| v)
+            .collect();
+        Ok((self.induced_subgraph(&vids)?, vids))
+    }
+
+    /// Extract the k-truss, the subgraph made of the edges with trussness
+    /// at least `k`.
+    ///
+    /// Returns the subgraph and, for each of its vertices, the id of the
+    /// corresponding vertex in this graph.
+    pub fn k_truss(&self, k: i64) -> Result<(Graph, Vec<i64>)> {
+        let eids: Vec<i64> = (0..)
+            .zip(self.trussness()?)
+            .filter(|&(_, t)| t >= k)
+            .map(|(e, _)| e)
+            .collect();
+        let mut vids = Vec::with_capacity(2 * eids.len());
+        for &e in &eids {
+            let (from, to) = self.edge(e)?;
+            vids.extend([from, to]);
+        }
+        vids.sort_unstable();
+        vids.dedup();
+        Ok((self.subgraph_from_edges(&eids, true)?, vids))
+    }
+}
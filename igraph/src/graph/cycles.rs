@@ -0,0 +1,280 @@
+use std::ops::ControlFlow;
+use std::os::raw::c_void;
+
+use igraph_sys::*;
+
+use super::Graph;
+use crate::callback::PanicSlot;
+use crate::error::{Error, Result, check};
+use crate::types::{Cycle, NeighborMode};
+use crate::vector::{VectorInt, VectorIntList, vector_int_slice};
+
+type CycleHandlerFn<'a> = dyn FnMut(&[i64], &[i64]) -> ControlFlow<()> + 'a;
+
+/// State shared with the cycle callback trampoline through igraph's `arg` pointer.
+struct CycleContext<'a> {
+    handler: &'a mut CycleHandlerFn<'a>,
+    panic: PanicSlot,
+}
+
+unsafe extern "C" fn cycle_trampoline(
+    vertices: *const igraph_vector_int_t,
+    edges: *const igraph_vector_int_t,
+    arg: *mut c_void,
+) -> igraph_error_t {
+    let ctx = unsafe { &mut *arg.cast::<CycleContext>() };
+    let (vertices, edges) = unsafe { (vector_int_slice(vertices), vector_int_slice(edges)) };
+    let handler = &mut ctx.handler;
+    ctx.panic.call_handler(|| handler(vertices, edges))
+}
+
+impl Graph {
+    /// Compute the girth, the length of the shortest cycle.
+    ///
+    /// Returns `(girth, vertices)` where `vertices` lists the vertices of one
+    /// shortest cycle. Acyclic graphs have infinite girth and an empty cycle.
+    /// Edge directions, self-loops and multi-edges are ignored.
+    pub fn girth(&self) -> Result<(f64, Vec<i64>)> {
+        let mut girth: f64 = 0.0;
+        let mut circle = VectorInt::new()?;
+        unsafe {
+            check(igraph_girth(self.as_ptr(), &mut girth, circle.as_mut_ptr()))?;
+        }
+        Ok((girth, circle.to_vec()))
+    }
+
+    /// Find a single cycle in the graph, if there is one.
+    ///
+    /// In directed graphs, `mode` selects whether edge directions are
+    /// followed (`Out` or `In`) or ignored (`All`).
+    pub fn find_cycle(&self, mode: NeighborMode) -> Result<Option<Cycle>> {
+        let mut vertices = VectorInt::new()?;
+        let mut edges = VectorInt::new()?;
+        unsafe {
+            check(igraph_find_cycle(
+                self.as_ptr(),
+                vertices.as_mut_ptr(),
+                edges.as_mut_ptr(),
+                mode.to_raw(),
+            ))?;
+        }
+        if edges.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Cycle {
+            vertices: vertices.to_vec(),
+            edges: edges.to_vec(),
+        }))
+    }
+
+    /// Compute a fundamental cycle basis.
+    ///
+    /// If `start_vid` is given, only the component containing it is
+    /// considered. `bfs_cutoff` limits the search to cycles of at most about
+    /// twice that length. Edge directions are ignored.
+    pub fn fundamental_cycles(
+        &self,
+        start_vid: Option<i64>,
+        bfs_cutoff: Option<f64>,
+    ) -> Result<Vec<Cycle>> {
+        if start_vid.is_some_and(|v| !(0..self.vcount()).contains(&v)) {
+            return Err(Error::InvalidVertexId);
+        }
+        let mut res = VectorIntList::new()?;
+        unsafe {
+            check(igraph_fundamental_cycles(
+                self.as_ptr(),
+                std::ptr::null(), // weights
+                res.as_mut_ptr(),
+                start_vid.unwrap_or(-1),
+                bfs_cutoff.unwrap_or(-1.0),
+            ))?;
+        }
+        Ok(self.cycles_from_edge_lists(res))
+    }
+
+    /// Compute a minimum cycle basis, e.g. the smallest set of smallest
+    /// rings of a molecule.
+    ///
+    /// `bfs_cutoff` restricts the candidate cycles to speed up the search;
+    /// with `complete`, a full basis is still guaranteed when a cutoff is
+    /// given. Edge directions are ignored.
+    ///
+    /// Edges are always listed in order along each cycle. igraph can instead
+    /// sort them by id, which is slightly cheaper, but the vertex sequence
+    /// of a [`Cycle`] cannot be recovered from sorted edges, so that option
+    /// is not offered.
+    pub fn minimum_cycle_basis(
+        &self,
+        bfs_cutoff: Option<f64>,
+        complete: bool,
+    ) -> Result<Vec<Cycle>> {
+        let mut res = VectorIntList::new()?;
+        unsafe {
+            check(igraph_minimum_cycle_basis(
+                self.as_ptr(),
+                std::ptr::null(), // weights
+                res.as_mut_ptr(),
+                bfs_cutoff.unwrap_or(-1.0),
+                complete,
+                true, // use_cycle_order, needed to recover the vertices
+            ))?;
+        }
+        Ok(self.cycles_from_edge_lists(res))
+    }
+
+    /// Turn cycles given as edge ids in cycle order into [`Cycle`]s by
+    /// walking the edges, ignoring their direction.
+    fn cycles_from_edge_lists(&self, list: VectorIntList) -> Vec<Cycle> {
+        let from = unsafe { vector_int_slice(&self.inner.from) };
+        let to = unsafe { vector_int_slice(&self.inner.to) };
+        let ends = |e: i64| (from[e as usize], to[e as usize]);
+        list.to_vec_of_vecs()
+            .into_iter()
+            .map(|edges| {
+                let Some(&first) = edges.first() else {
+                    return Cycle {
+                        vertices: Vec::new(),
+                        edges,
+                    };
+                };
+                // Start at the end of the first edge that the second edge
+                // does not touch, so the walk leaves through the first edge.
+                let (a, b) = ends(first);
+                let mut cur = match edges.get(1).map(|&e| ends(e)) {
+                    Some((c, d)) if a != c && a != d => a,
+                    Some(_) if edges.len() > 2 => b,
+                    _ => a,
+                };
+                let vertices = edges
+                    .iter()
+                    .map(|&e| {
+                        let v = cur;
+                        let (a, b) = ends(e);
+                        cur = if a == cur { b } else { a };
+                        v
+                    })
+                    .collect();
+                Cycle { vertices, edges }
+            })
+            .collect()
+    }
+
+    /// Find all simple cycles with a length (number of edges) between
+    /// `min_length` and `max_length`, both inclusive.
+    ///
+    /// `None` leaves that side unbounded. In directed graphs, `mode` selects
+    /// whether edge directions are followed or ignored.
+    pub fn simple_cycles(
+        &self,
+        mode: NeighborMode,
+        min_length: Option<i64>,
+        max_length: Option<i64>,
+    ) -> Result<Vec<Cycle>> {
+        let mut vertices = VectorIntList::new()?;
+        let mut edges = VectorIntList::new()?;
+        unsafe {
+            check(igraph_simple_cycles(
+                self.as_ptr(),
+                vertices.as_mut_ptr(),
+                edges.as_mut_ptr(),
+                mode.to_raw(),
+                min_length.unwrap_or(-1),
+                max_length.unwrap_or(-1),
+                IGRAPH_UNLIMITED as i64, // max_results
+            ))?;
+        }
+        Ok(vertices
+            .to_vec_of_vecs()
+            .into_iter()
+            .zip(edges.to_vec_of_vecs())
+            .map(|(vertices, edges)| Cycle { vertices, edges })
+            .collect())
+    }
+
+    /// Stream the simple cycles found by [`Graph::simple_cycles`].
+    ///
+    /// `handler` is called with the vertices and edges of each cycle as it is
+    /// found; returning [`ControlFlow::Break`] stops the search.
+    pub fn simple_cycles_callback(
+        &self,
+        mode: NeighborMode,
+        min_length: Option<i64>,
+        max_length: Option<i64>,
+        mut handler: impl FnMut(&[i64], &[i64]) -> ControlFlow<()>,
+    ) -> Result<()> {
+        let mut ctx = CycleContext {
+            handler: &mut handler,
+            panic: PanicSlot::default(),
+        };
+        let code = unsafe {
+            igraph_simple_cycles_callback(
+                self.as_ptr(),
+                mode.to_raw(),
+                min_length.unwrap_or(-1),
+                max_length.unwrap_or(-1),
+                Some(cycle_trampoline),
+                (&mut ctx as *mut CycleContext).cast(),
+            )
+        };
+        ctx.panic.resume();
+        check(code)
+    }
+
+    /// Check whether the graph has no cycles.
+    ///
+    /// For directed graphs this is the same as [`Graph::is_dag`]; for
+    /// undirected graphs it is the same as [`Graph::is_forest`].
+    pub fn is_acyclic(&self) -> Result<bool> {
+        let mut res: bool = false;
+        unsafe {
+            check(igraph_is_acyclic(self.as_ptr(), &mut res))?;
+        }
+        Ok(res)
+    }
+
+    /// Check whether the graph is a forest, i.e. every component is a tree.
+    ///
+    /// For directed graphs, `mode` selects out-trees, in-trees, or ignoring
+    /// edge directions.
+    pub fn is_forest(&self, mode: NeighborMode) -> Result<bool> {
+        let mut res: bool = false;
+        unsafe {
+            check(igraph_is_forest(
+                self.as_ptr(),
+                &mut res,
+                std::ptr::null_mut(), // roots
+                mode.to_raw(),
+            ))?;
+        }
+        Ok(res)
+    }
+
+    /// Check whether the graph is a tree, i.e. connected and acyclic.
+    ///
+    /// For directed graphs, `mode` selects out-trees, in-trees, or ignoring
+    /// edge directions.
+    pub fn is_tree(&self, mode: NeighborMode) -> Result<bool> {
+        let mut res: bool = false;
+        unsafe {
+            check(igraph_is_tree(
+                self.as_ptr(),
+                &mut res,
+                std::ptr::null_mut(), // root
+                mode.to_raw(),
+            ))?;
+        }
+        Ok(res)
+    }
+
+    /// Check whether the graph is a directed acyclic graph.
+    ///
+    /// Undirected graphs are never DAGs.
+    pub fn is_dag(&self) -> Result<bool> {
+        let mut res: bool = false;
+        unsafe {
+            check(igraph_is_dag(self.as_ptr(), &mut res))?;
+        }
+        Ok(res)
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::VecDeque;
+
+use igraph_sys::*;
+
+use super::Graph;
+use crate::error::{Error, Result, check};
+use crate::types::{FasAlgorithm, NeighborMode};
+use crate::vector::{Vector, VectorInt};
+
+impl Graph {
+    /// Compute a topological ordering of the vertices of a directed graph.
+    ///
+    /// With `NeighborMode::Out`, every vertex comes before the targets of its
+    /// out-edges; with `NeighborMode::In`, the order is reversed.
+    ///
+    /// If the graph has a cycle, this fails with [`Error::InvalidValue`]
+    /// unless `partial` is set, in which case a partial order is returned
+    /// that omits the vertices on or reachable from a cycle.
+    pub fn topological_sorting(&self, mode: NeighborMode, partial: bool) -> Result<Vec<i64>> {
+        if mode == NeighborMode::All {
+            return Err(Error::InvalidMode);
+        }
+        if !self.is_directed() {
+            return Err(Error::InvalidValue);
+        }
+        if !self.is_dag()? {
+            if partial {
+                return self.partial_topological_sorting(mode);
+            }
+            return Err(Error::InvalidValue);
+        }
+        let mut res = VectorInt::new()?;
+        unsafe {
+            check(igraph_topological_sorting(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                mode.to_raw(),
+            ))?;
+        }
+        Ok(res.to_vec())
+    }
+
+    /// Kahn's algorithm, stopping once only vertices on or downstream of a
+    /// cycle remain.
+    fn partial_topological_sorting(&self, mode: NeighborMode) -> Result<Vec<i64>> {
+        let n = self.vcount() as usize;
+        let mut indegree = vec![0usize; n];
+        let mut successors = vec![Vec::new(); n];
+        for (from, to) in self.get_edgelist()? {
+            let (from, to) = match mode {
+                NeighborMode::In => (to as usize, from as usize),
+                _ => (from as usize, to as usize),
+            };
+            successors[from].push(to);
+            indegree[to] += 1;
+        }
+        let mut queue: VecDeque<usize> = (0..n).filter(|&v| indegree[v] == 0).collect();
+        let mut order = Vec::with_capacity(n);
+        while let Some(v) = queue.pop_front() {
+            order.push(v as i64);
+            for &w in &successors[v] {
+                indegree[w] -= 1;
+                if indegree[w] == 0 {
+                    queue.push_back(w);
+                }
+            }
+        }
+        Ok(order)
+    }
+
+    /// Find a feedback arc set, a set of edges whose removal makes the graph
+    /// acyclic.
+    ///
+    /// The exact algorithms find a set of minimum total `weights` (or minimum
+    /// size without weights); Eades' heuristic is fast but approximate. For
+    /// undirected graphs, the complement of a spanning forest is returned.
+    pub fn feedback_arc_set(
+        &self,
+        weights: Option<&[f64]>,
+        algo: FasAlgorithm,
+    ) -> Result<Vec<i64>> {
+        let weights = weights.map(Vector::from_slice).transpose()?;
+        let mut res = VectorInt::new()?;
+        unsafe {
+            check(igraph_feedback_arc_set(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                weights.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                algo.to_raw(),
+            ))?;
+        }
+        Ok(res.to_vec())
+    }
+
+    /// Find a minimum feedback vertex set, a set of vertices whose removal
+    /// makes the graph acyclic.
+    ///
+    /// Minimizes the total `vertex_weights`, or the set size without weights,
+    /// using an exact integer programming approach.
+    pub fn feedback_vertex_set(&self, vertex_weights: Option<&[f64]>) -> Result<Vec<i64>> {
+        let weights = vertex_weights.map(Vector::from_slice).transpose()?;
+        let mut res = VectorInt::new()?;
+        unsafe {
+            check(igraph_feedback_vertex_set(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                weights.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                igraph_fvs_algorithm_t_IGRAPH_FVS_EXACT_IP,
+            ))?;
+        }
+        Ok(res.to_vec())
+    }
+}
@@ -0,0 +1,69 @@
+use igraph_sys::*;
+
+use super::Graph;
+use crate::error::{Result, check};
+use crate::vector::VectorInt;
+
+impl Graph {
+    /// Check whether the graph has an Eulerian path and/or an Eulerian cycle.
+    ///
+    /// Returns `(has_path, has_cycle)`. An Eulerian path traverses every edge
+    /// exactly once; an Eulerian cycle is one that ends where it started.
+    /// Self-loops and multi-edges are supported.
+    pub fn is_eulerian(&self) -> Result<(bool, bool)> {
+        let mut has_path: bool = false;
+        let mut has_cycle: bool = false;
+        unsafe {
+            check(igraph_is_eulerian(
+                self.as_ptr(),
+                &mut has_path,
+                &mut has_cycle,
+            ))?;
+        }
+        Ok((has_path, has_cycle))
+    }
+
+    /// Find an Eulerian path.
+    ///
+    /// Returns `Some((edges, vertices))` with the edge ids in traversal order
+    /// and the visited vertices (one more than the edges), or `None` if the
+    /// graph has no Eulerian path.
+    pub fn eulerian_path(&self) -> Result<Option<(Vec<i64>, Vec<i64>)>> {
+        let (has_path, _) = self.is_eulerian()?;
+        if !has_path {
+            return Ok(None);
+        }
+        let mut edges = VectorInt::new()?;
+        let mut vertices = VectorInt::new()?;
+        unsafe {
+            check(igraph_eulerian_path(
+                self.as_ptr(),
+                edges.as_mut_ptr(),
+                vertices.as_mut_ptr(),
+            ))?;
+        }
+        Ok(Some((edges.to_vec(), vertices.to_vec())))
+    }
+
+    /// Find an Eulerian cycle.
+    ///
+    /// Returns `Some((edges, vertices))` with the edge ids in traversal order
+    /// and the visited vertices, where the first vertex is repeated at the
+    /// end, or `None` if the graph has no Eulerian cycle.
+    pub fn eulerian_cycle(&self) -> Result<Option<(Vec<i64>, Vec<i64>)>> {
+        let (_, has_cycle) = self.is_eulerian()?;
+        if !has_cycle {
+            return Ok(None);
+        }
+        let mut edges = VectorInt::new()?;
+        let mut vertices = VectorInt::new()?;
+        unsafe {
+            check(igraph_eulerian_cycle(
+                self.as_ptr(),
+                edges.as_mut_ptr(),
+                vertices.as_mut_ptr(),
+            ))?;
+        }
+        Ok(Some((edges.to_vec(), vertices.to_vec())))
+    }
+}
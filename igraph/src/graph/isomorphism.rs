@@ -1,27 +1,371 @@
+use std::mem::MaybeUninit;
+use std::ops::ControlFlow;
+use std::os::raw::c_void;
+use std::time::Duration;
+
 use igraph_sys::*;
 
 use super::Graph;
+use crate::callback::{DeadlineGuard, PanicSlot};
 use crate::error::{Result, check};
-use crate::vector::VectorIntList;
+use crate::types::{BlissInfo, BlissSplitHeuristic, Vf2Colors, Vf2CompatFn, Vf2HandlerFn};
+use crate::vector::{VectorInt, VectorIntList, vector_int_slice};
+
+/// igraph-owned copies of [`Vf2Colors`], kept alive for the duration of a call.
+struct RawColors {
+    vertex1: Option<VectorInt>,
+    vertex2: Option<VectorInt>,
+    edge1: Option<VectorInt>,
+    edge2: Option<VectorInt>,
+}
+
+impl RawColors {
+    fn new(colors: &Vf2Colors) -> Result<Self> {
+        Ok(Self {
+            vertex1: colors.vertex1.map(VectorInt::from_slice).transpose()?,
+            vertex2: colors.vertex2.map(VectorInt::from_slice).transpose()?,
+            edge1: colors.edge1.map(VectorInt::from_slice).transpose()?,
+            edge2: colors.edge2.map(VectorInt::from_slice).transpose()?,
+        })
+    }
+
+    fn ptr(v: &Option<VectorInt>) -> *const igraph_vector_int_t {
+        v.as_ref().map_or(std::ptr::null(), |v| v.as_ptr())
+    }
+
+    fn vertex1(&self) -> *const igraph_vector_int_t {
+        Self::ptr(&self.vertex1)
+    }
+
+    fn vertex2(&self) -> *const igraph_vector_int_t {
+        Self::ptr(&self.vertex2)
+    }
+
+    fn edge1(&self) -> *const igraph_vector_int_t {
+        Self::ptr(&self.edge1)
+    }
+
+    fn edge2(&self) -> *const igraph_vector_int_t {
+        Self::ptr(&self.edge2)
+    }
+}
+
+/// State shared with the VF2 callback trampolines through igraph's `arg` pointer.
+struct Vf2Context<'a> {
+    graph1: &'a Graph,
+    graph2: &'a Graph,
+    node_compat: Option<&'a mut Vf2CompatFn<'a>>,
+    edge_compat: Option<&'a mut Vf2CompatFn<'a>>,
+    handler: Option<&'a mut Vf2HandlerFn<'a>>,
+    panic: PanicSlot,
+}
+
+impl<'a> Vf2Context<'a> {
+    fn new(
+        graph1: &'a Graph,
+        graph2: &'a Graph,
+        node_compat: Option<&'a mut Vf2CompatFn<'_>>,
+        edge_compat: Option<&'a mut Vf2CompatFn<'_>>,
+    ) -> Self {
+        Self {
+            graph1,
+            graph2,
+            node_compat: node_compat.map(|f| f as &mut Vf2CompatFn),
+            edge_compat: edge_compat.map(|f| f as &mut Vf2CompatFn),
+            handler: None,
+            panic: PanicSlot::default(),
+        }
+    }
+
+    fn with_handler(mut self, handler: &'a mut Vf2HandlerFn<'_>) -> Self {
+        self.handler = Some(handler as &mut Vf2HandlerFn);
+        self
+    }
+
+    fn node_compat_fn(&self) -> igraph_isocompat_t {
+        match self.node_compat {
+            Some(_) => Some(node_compat_trampoline),
+            None => None,
+        }
+    }
+
+    fn edge_compat_fn(&self) -> igraph_isocompat_t {
+        match self.edge_compat {
+            Some(_) => Some(edge_compat_trampoline),
+            None => None,
+        }
+    }
+
+    fn as_arg(&mut self) -> *mut c_void {
+        (self as *mut Self).cast()
+    }
+
+    /// Re-raise any panic from a callback, then check the igraph return code.
+    fn finish(&mut self, code: igraph_error_t) -> Result<()> {
+        self.panic.resume();
+        check(code)
+    }
+}
+
+unsafe extern "C" fn node_compat_trampoline(
+    _graph1: *const igraph_t,
+    _graph2: *const igraph_t,
+    g1_num: igraph_int_t,
+    g2_num: igraph_int_t,
+    arg: *mut c_void,
+) -> igraph_bool_t {
+    let ctx = unsafe { &mut *arg.cast::<Vf2Context>() };
+    let (graph1, graph2) = (ctx.graph1, ctx.graph2);
+    match ctx.node_compat.as_mut() {
+        Some(f) => ctx
+            .panic
+            .call(|| f(graph1, graph2, g1_num, g2_num))
+            .unwrap_or(false),
+        None => true,
+    }
+}
+
+unsafe extern "C" fn edge_compat_trampoline(
+    _graph1: *const igraph_t,
+    _graph2: *const igraph_t,
+    g1_num: igraph_int_t,
+    g2_num: igraph_int_t,
+    arg: *mut c_void,
+) -> igraph_bool_t {
+    let ctx = unsafe { &mut *arg.cast::<Vf2Context>() };
+    let (graph1, graph2) = (ctx.graph1, ctx.graph2);
+    match ctx.edge_compat.as_mut() {
+        Some(f) => ctx
+            .panic
+            .call(|| f(graph1, graph2, g1_num, g2_num))
+            .unwrap_or(false),
+        None => true,
+    }
+}
+
+unsafe extern "C" fn handler_trampoline(
+    map12: *const igraph_vector_int_t,
+    map21: *const igraph_vector_int_t,
+    arg: *mut c_void,
+) -> igraph_error_t {
+    let ctx = unsafe { &mut *arg.cast::<Vf2Context>() };
+    let (map12, map21) = unsafe { (vector_int_slice(map12), vector_int_slice(map21)) };
+    let Some(f) = ctx.handler.as_mut() else {
+        return igraph_error_type_t_IGRAPH_SUCCESS;
+    };
+    ctx.panic.call_handler(|| f(map12, map21))
+}
 
 impl Graph {
+    /// Check whether this graph is isomorphic to `other`.
+    ///
+    /// igraph picks a suitable algorithm based on the graphs' properties.
+    pub fn isomorphic(&self, other: &Graph) -> Result<bool> {
+        let mut iso: bool = false;
+        unsafe {
+            check(igraph_isomorphic(self.as_ptr(), other.as_ptr(), &mut iso))?;
+        }
+        Ok(iso)
+    }
+
+    /// Check whether this graph is isomorphic to `other` using VF2.
+    ///
+    /// Returns `Some((map12, map21))` if the graphs are isomorphic, where
+    /// `map12[v]` is the vertex of `other` matched to vertex `v` of `self`
+    /// and `map21` is the inverse mapping. Returns `None` otherwise.
+    pub fn isomorphic_vf2(
+        &self,
+        other: &Graph,
+        colors: &Vf2Colors,
+        node_compat: Option<&mut Vf2CompatFn<'_>>,
+        edge_compat: Option<&mut Vf2CompatFn<'_>>,
+    ) -> Result<Option<(Vec<i64>, Vec<i64>)>> {
+        let colors = RawColors::new(colors)?;
+        let mut ctx = Vf2Context::new(self, other, node_compat, edge_compat);
+        let mut iso: bool = false;
+        let mut map12 = VectorInt::new()?;
+        let mut map21 = VectorInt::new()?;
+        let code = unsafe {
+            igraph_isomorphic_vf2(
+                self.as_ptr(),
+                other.as_ptr(),
+                colors.vertex1(),
+                colors.vertex2(),
+                colors.edge1(),
+                colors.edge2(),
+                &mut iso,
+                map12.as_mut_ptr(),
+                map21.as_mut_ptr(),
+                ctx.node_compat_fn(),
+                ctx.edge_compat_fn(),
+                ctx.as_arg(),
+            )
+        };
+        ctx.finish(code)?;
+        Ok(iso.then(|| (map12.to_vec(), map21.to_vec())))
+    }
+
+    /// Count the number of isomorphisms between this graph and `other` using VF2.
+    pub fn count_isomorphisms_vf2(
+        &self,
+        other: &Graph,
+        colors: &Vf2Colors,
+        node_compat: Option<&mut Vf2CompatFn<'_>>,
+        edge_compat: Option<&mut Vf2CompatFn<'_>>,
+    ) -> Result<i64> {
+        let colors = RawColors::new(colors)?;
+        let mut ctx = Vf2Context::new(self, other, node_compat, edge_compat);
+        let mut count: i64 = 0;
+        let code = unsafe {
+            igraph_count_isomorphisms_vf2(
+                self.as_ptr(),
+                other.as_ptr(),
+                colors.vertex1(),
+                colors.vertex2(),
+                colors.edge1(),
+                colors.edge2(),
+                &mut count,
+                ctx.node_compat_fn(),
+                ctx.edge_compat_fn(),
+                ctx.as_arg(),
+            )
+        };
+        ctx.finish(code)?;
+        Ok(count)
+    }
+
+    /// Get all isomorphism mappings between this graph and `other` using VF2.
+    ///
+    /// Each mapping is a vector of vertex indices in `self` corresponding to
+    /// the vertices of `other`.
+    pub fn get_isomorphisms_vf2(
+        &self,
+        other: &Graph,
+        colors: &Vf2Colors,
+        node_compat: Option<&mut Vf2CompatFn<'_>>,
+        edge_compat: Option<&mut Vf2CompatFn<'_>>,
+    ) -> Result<Vec<Vec<i64>>> {
+        let colors = RawColors::new(colors)?;
+        let mut ctx = Vf2Context::new(self, other, node_compat, edge_compat);
+        let mut maps = VectorIntList::new()?;
+        let code = unsafe {
+            igraph_get_isomorphisms_vf2(
+                self.as_ptr(),
+                other.as_ptr(),
+                colors.vertex1(),
+                colors.vertex2(),
+                colors.edge1(),
+                colors.edge2(),
+                maps.as_mut_ptr(),
+                ctx.node_compat_fn(),
+                ctx.edge_compat_fn(),
+                ctx.as_arg(),
+            )
+        };
+        ctx.finish(code)?;
+        Ok(maps.to_vec_of_vecs())
+    }
+
+    /// Stream the isomorphisms between this graph and `other` using VF2.
+    ///
+    /// `handler` is called with `(map12, map21)` for every isomorphism as it
+    /// is found, without collecting them; returning [`ControlFlow::Break`]
+    /// stops the search.
+    pub fn get_isomorphisms_vf2_callback(
+        &self,
+        other: &Graph,
+        colors: &Vf2Colors,
+        node_compat: Option<&mut Vf2CompatFn<'_>>,
+        edge_compat: Option<&mut Vf2CompatFn<'_>>,
+        mut handler: impl FnMut(&[i64], &[i64]) -> ControlFlow<()>,
+    ) -> Result<()> {
+        let colors = RawColors::new(colors)?;
+        let mut ctx =
+            Vf2Context::new(self, other, node_compat, edge_compat).with_handler(&mut handler);
+        let mut map12 = VectorInt::new()?;
+        let mut map21 = VectorInt::new()?;
+        let code = unsafe {
+            igraph_get_isomorphisms_vf2_callback(
+                self.as_ptr(),
+                other.as_ptr(),
+                colors.vertex1(),
+                colors.vertex2(),
+                colors.edge1(),
+                colors.edge2(),
+                map12.as_mut_ptr(),
+                map21.as_mut_ptr(),
+                Some(handler_trampoline),
+                ctx.node_compat_fn(),
+                ctx.edge_compat_fn(),
+                ctx.as_arg(),
+            )
+        };
+        ctx.finish(code)
+    }
+
+    /// Check whether `pattern` is isomorphic to a subgraph of this graph using VF2.
+    ///
+    /// Returns `Some((map12, map21))` for the first match found, where
+    /// `map21[v]` is the vertex of `self` matched to vertex `v` of `pattern`
+    /// and `map12` holds `-1` for unmatched vertices of `self`.
+    pub fn subisomorphic_vf2(
+        &self,
+        pattern: &Graph,
+        colors: &Vf2Colors,
+        node_compat: Option<&mut Vf2CompatFn<'_>>,
+        edge_compat: Option<&mut Vf2CompatFn<'_>>,
+    ) -> Result<Option<(Vec<i64>, Vec<i64>)>> {
+        let colors = RawColors::new(colors)?;
+        let mut ctx = Vf2Context::new(self, pattern, node_compat, edge_compat);
+        let mut iso: bool = false;
+        let mut map12 = VectorInt::new()?;
+        let mut map21 = VectorInt::new()?;
+        let code = unsafe {
+            igraph_subisomorphic_vf2(
+                self.as_ptr(),
+                pattern.as_ptr(),
+                colors.vertex1(),
+                colors.vertex2(),
+                colors.edge1(),
+                colors.edge2(),
+                &mut iso,
+                map12.as_mut_ptr(),
+                map21.as_mut_ptr(),
+                ctx.node_compat_fn(),
+                ctx.edge_compat_fn(),
+                ctx.as_arg(),
+            )
+        };
+        ctx.finish(code)?;
+        Ok(iso.then(|| (map12.to_vec(), map21.to_vec())))
+    }
+
     /// Count the number of subisomorphisms of `pattern` in this graph using VF2.
-    pub fn count_subisomorphisms_vf2(&self, pattern: &Graph) -> Result<i64> {
+    pub fn count_subisomorphisms_vf2(
+        &self,
+        pattern: &Graph,
+        colors: &Vf2Colors,
+        node_compat: Option<&mut Vf2CompatFn<'_>>,
+        edge_compat: Option<&mut Vf2CompatFn<'_>>,
+    ) -> Result<i64> {
+        let colors = RawColors::new(colors)?;
+        let mut ctx = Vf2Context::new(self, pattern, node_compat, edge_compat);
         let mut count: i64 = 0;
-        unsafe {
-            check(igraph_count_subisomorphisms_vf2(
+        let code = unsafe {
+            igraph_count_subisomorphisms_vf2(
                 self.as_ptr(),
                 pattern.as_ptr(),
-                std::ptr::null(), // vertex_color1
-                std::ptr::null(), // vertex_color2
-                std::ptr::null(), // edge_color1
-                std::ptr::null(), // edge_color2
+                colors.vertex1(),
+                colors.vertex2(),
+                colors.edge1(),
+                colors.edge2(),
                 &mut count,
-                None, // node_compat_fn
-                None, // edge_compat_fn
-                std::ptr::null_mut(),
-            ))?;
-        }
+                ctx.node_compat_fn(),
+                ctx.edge_compat_fn(),
+                ctx.as_arg(),
+            )
+        };
+        ctx.finish(code)?;
         Ok(count)
     }
 
@@ -29,22 +373,285 @@ impl Graph {
     ///
     /// Returns a list of mappings, where each mapping is a vector of vertex
     /// indices in `self` corresponding to the vertices of `pattern`.
-    pub fn get_subisomorphisms_vf2(&self, pattern: &Graph) -> Result<Vec<Vec<i64>>> {
+    pub fn get_subisomorphisms_vf2(
+        &self,
+        pattern: &Graph,
+        colors: &Vf2Colors,
+        node_compat: Option<&mut Vf2CompatFn<'_>>,
+        edge_compat: Option<&mut Vf2CompatFn<'_>>,
+    ) -> Result<Vec<Vec<i64>>> {
+        let colors = RawColors::new(colors)?;
+        let mut ctx = Vf2Context::new(self, pattern, node_compat, edge_compat);
         let mut maps = VectorIntList::new()?;
+        let code = unsafe {
+            igraph_get_subisomorphisms_vf2(
+                self.as_ptr(),
+                pattern.as_ptr(),
+                colors.vertex1(),
+                colors.vertex2(),
+                colors.edge1(),
+                colors.edge2(),
+                maps.as_mut_ptr(),
+                ctx.node_compat_fn(),
+                ctx.edge_compat_fn(),
+                ctx.as_arg(),
+            )
+        };
+        ctx.finish(code)?;
+        Ok(maps.to_vec_of_vecs())
+    }
+
+    /// Stream the subisomorphisms of `pattern` in this graph using VF2.
+    ///
+    /// `handler` is called with `(map12, map21)` for every match as it is
+    /// found, where `map21[v]` is the vertex of `self` matched to vertex `v`
+    /// of `pattern`. Returning [`ControlFlow::Break`] stops the search, which
+    /// makes first-match and limit-N queries cheap on large host graphs.
+    pub fn get_subisomorphisms_vf2_callback(
+        &self,
+        pattern: &Graph,
+        colors: &Vf2Colors,
+        node_compat: Option<&mut Vf2CompatFn<'_>>,
+        edge_compat: Option<&mut Vf2CompatFn<'_>>,
+        mut handler: impl FnMut(&[i64], &[i64]) -> ControlFlow<()>,
+    ) -> Result<()> {
+        let colors = RawColors::new(colors)?;
+        let mut ctx =
+            Vf2Context::new(self, pattern, node_compat, edge_compat).with_handler(&mut handler);
+        let mut map12 = VectorInt::new()?;
+        let mut map21 = VectorInt::new()?;
+        let code = unsafe {
+            igraph_get_subisomorphisms_vf2_callback(
+                self.as_ptr(),
+                pattern.as_ptr(),
+                colors.vertex1(),
+                colors.vertex2(),
+                colors.edge1(),
+                colors.edge2(),
+                map12.as_mut_ptr(),
+                map21.as_mut_ptr(),
+                Some(handler_trampoline),
+                ctx.node_compat_fn(),
+                ctx.edge_compat_fn(),
+                ctx.as_arg(),
+            )
+        };
+        ctx.finish(code)
+    }
+
+    /// Check whether `pattern` is isomorphic to a subgraph of this graph using LAD.
+    ///
+    /// Returns the first match found as a vector mapping each vertex of
+    /// `pattern` to a vertex of `self`, or `None` if there is none.
+    ///
+    /// `domains`, if given, holds for every pattern vertex the list of target
+    /// vertices it may be matched to. With `induced`, only induced subgraphs
+    /// match, i.e. non-adjacent pattern vertices must map to non-adjacent
+    /// vertices. If `time_limit` elapses before the search completes, the
+    /// call fails with [`Error::Interrupted`](crate::Error::Interrupted).
+    pub fn subisomorphic_lad(
+        &self,
+        pattern: &Graph,
+        domains: Option<&[Vec<i64>]>,
+        induced: bool,
+        time_limit: Option<Duration>,
+    ) -> Result<Option<Vec<i64>>> {
+        let domains = domains.map(VectorIntList::from_slices).transpose()?;
+        let mut iso: bool = false;
+        let mut map = VectorInt::new()?;
+        let _deadline = time_limit.map(DeadlineGuard::new);
         unsafe {
-            check(igraph_get_subisomorphisms_vf2(
+            check(igraph_subisomorphic_lad(
+                pattern.as_ptr(),
                 self.as_ptr(),
+                domains.as_ref().map_or(std::ptr::null(), |d| d.as_ptr()),
+                &mut iso,
+                map.as_mut_ptr(),
+                std::ptr::null_mut(), // maps
+                induced,
+            ))?;
+        }
+        Ok(iso.then(|| map.to_vec()))
+    }
+
+    /// Get all subisomorphism mappings of `pattern` in this graph using LAD.
+    ///
+    /// Each mapping is a vector of vertex indices in `self` corresponding to
+    /// the vertices of `pattern`. See [`Graph::subisomorphic_lad`] for the
+    /// meaning of `domains`, `induced` and `time_limit`.
+    pub fn get_subisomorphisms_lad(
+        &self,
+        pattern: &Graph,
+        domains: Option<&[Vec<i64>]>,
+        induced: bool,
+        time_limit: Option<Duration>,
+    ) -> Result<Vec<Vec<i64>>> {
+        let domains = domains.map(VectorIntList::from_slices).transpose()?;
+        let mut iso: bool = false;
+        let mut maps = VectorIntList::new()?;
+        let _deadline = time_limit.map(DeadlineGuard::new);
+        unsafe {
+            check(igraph_subisomorphic_lad(
                 pattern.as_ptr(),
-                std::ptr::null(), // vertex_color1
-                std::ptr::null(), // vertex_color2
-                std::ptr::null(), // edge_color1
-                std::ptr::null(), // edge_color2
+                self.as_ptr(),
+                domains.as_ref().map_or(std::ptr::null(), |d| d.as_ptr()),
+                &mut iso,
+                std::ptr::null_mut(), // map
                 maps.as_mut_ptr(),
-                None, // node_compat_fn
-                None, // edge_compat_fn
-                std::ptr::null_mut(),
+                induced,
             ))?;
         }
         Ok(maps.to_vec_of_vecs())
     }
+
+    /// Return the isomorphism class of the graph.
+    ///
+    /// Only graphs with 3 or 4 vertices, or undirected graphs with 5 or 6
+    /// vertices, are supported.
+    pub fn isoclass(&self) -> Result<i64> {
+        let mut isoclass: i64 = 0;
+        unsafe {
+            check(igraph_isoclass(self.as_ptr(), &mut isoclass))?;
+        }
+        Ok(isoclass)
+    }
+
+    /// Return the isomorphism class of the subgraph induced by `vids`.
+    pub fn isoclass_subgraph(&self, vids: &[i64]) -> Result<i64> {
+        let v = VectorInt::from_slice(vids)?;
+        let mut isoclass: i64 = 0;
+        unsafe {
+            check(igraph_isoclass_subgraph(
+                self.as_ptr(),
+                igraph_vss_vector(v.as_ptr()),
+                &mut isoclass,
+            ))?;
+        }
+        Ok(isoclass)
+    }
+
+    /// Create a graph with `size` vertices from its isomorphism class `number`.
+    pub fn isoclass_create(size: i64, number: i64, directed: bool) -> Result<Graph> {
+        Graph::init_with(|g| unsafe { igraph_isoclass_create(g, size, number, directed) })
+    }
+
+    /// Return the number of unlabeled graphs on `n` vertices, i.e. the number
+    /// of isomorphism classes.
+    pub fn graph_count(n: i64, directed: bool) -> Result<i64> {
+        let mut count: i64 = 0;
+        unsafe {
+            check(igraph_graph_count(n, directed, &mut count))?;
+        }
+        Ok(count)
+    }
+
+    /// Compute a canonical vertex labeling using BLISS.
+    ///
+    /// Returns `(labeling, info)` where `labeling[v]` is the new id of vertex
+    /// `v`. Passing `labeling` to [`Graph::permute_vertices`] yields a canonical
+    /// form: two graphs are isomorphic exactly when their canonical forms have
+    /// identical edge lists. Only vertices of equal `colors` may be mapped to
+    /// each other. Directed graphs are supported, multigraphs are not.
+    pub fn canonical_permutation_bliss(
+        &self,
+        colors: Option<&[i64]>,
+        sh: BlissSplitHeuristic,
+    ) -> Result<(Vec<i64>, BlissInfo)> {
+        let colors = colors.map(VectorInt::from_slice).transpose()?;
+        let colors_ptr = colors.as_ref().map_or(std::ptr::null(), |v| v.as_ptr());
+        let mut labeling = VectorInt::new()?;
+        let mut info = MaybeUninit::<igraph_bliss_info_t>::zeroed();
+        unsafe {
+            let code = igraph_canonical_permutation_bliss(
+                self.as_ptr(),
+                colors_ptr,
+                labeling.as_mut_ptr(),
+                sh.to_raw(),
+                info.as_mut_ptr(),
+            );
+            let info = BlissInfo::from_raw(info.assume_init());
+            check(code)?;
+            Ok((labeling.to_vec(), info))
+        }
+    }
+
+    /// Check whether this graph is isomorphic to `other` using BLISS.
+    ///
+    /// Returns `Some((map12, map21))` if the graphs are isomorphic and `None`
+    /// otherwise. Only vertices of equal color may be matched.
+    pub fn isomorphic_bliss(
+        &self,
+        other: &Graph,
+        colors1: Option<&[i64]>,
+        colors2: Option<&[i64]>,
+        sh: BlissSplitHeuristic,
+    ) -> Result<Option<(Vec<i64>, Vec<i64>)>> {
+        let colors1 = colors1.map(VectorInt::from_slice).transpose()?;
+        let colors2 = colors2.map(VectorInt::from_slice).transpose()?;
+        let mut iso: bool = false;
+        let mut map12 = VectorInt::new()?;
+        let mut map21 = VectorInt::new()?;
+        unsafe {
+            check(igraph_isomorphic_bliss(
+                self.as_ptr(),
+                other.as_ptr(),
+                colors1.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                colors2.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                &mut iso,
+                map12.as_mut_ptr(),
+                map21.as_mut_ptr(),
+                sh.to_raw(),
+                std::ptr::null_mut(), // info1
+                std::ptr::null_mut(), // info2
+            ))?;
+        }
+        Ok(iso.then(|| (map12.to_vec(), map21.to_vec())))
+    }
+
+    /// Compute the size of the automorphism group using BLISS.
+    ///
+    /// The group size is reported in [`BlissInfo::group_size`].
+    pub fn count_automorphisms_bliss(
+        &self,
+        colors: Option<&[i64]>,
+        sh: BlissSplitHeuristic,
+    ) -> Result<BlissInfo> {
+        let colors = colors.map(VectorInt::from_slice).transpose()?;
+        let colors_ptr = colors.as_ref().map_or(std::ptr::null(), |v| v.as_ptr());
+        let mut info = MaybeUninit::<igraph_bliss_info_t>::zeroed();
+        unsafe {
+            let code = igraph_count_automorphisms_bliss(
+                self.as_ptr(),
+                colors_ptr,
+                sh.to_raw(),
+                info.as_mut_ptr(),
+            );
+            let info = BlissInfo::from_raw(info.assume_init());
+            check(code)?;
+            Ok(info)
+        }
+    }
+
+    /// Compute a generating set of the automorphism group using BLISS.
+    ///
+    /// Each generator is a permutation of the vertices.
+    pub fn automorphism_group_bliss(
+        &self,
+        colors: Option<&[i64]>,
+        sh: BlissSplitHeuristic,
+    ) -> Result<Vec<Vec<i64>>> {
+        let colors = colors.map(VectorInt::from_slice).transpose()?;
+        let colors_ptr = colors.as_ref().map_or(std::ptr::null(), |v| v.as_ptr());
+        let mut generators = VectorIntList::new()?;
+        unsafe {
+            check(igraph_automorphism_group_bliss(
+                self.as_ptr(),
+                colors_ptr,
+                generators.as_mut_ptr(),
+                sh.to_raw(),
+                std::ptr::null_mut(), // info
+            ))?;
+        }
+        Ok(generators.to_vec_of_vecs())
+    }
 }
@@ -0,0 +1,175 @@
+use std::iter::FusedIterator;
+use std::mem::MaybeUninit;
+use std::ops::Range;
+
+use igraph_sys::*;
+
+use super::Graph;
+use crate::error::{Result, check};
+use crate::selector::{EdgeSelector, VertexSelector};
+use crate::vector::vector_int_slice;
+
+/// The ids picked by an igraph vertex or edge iterator, which is either a
+/// plain range or a list of ids.
+enum SelectedIds {
+    Range(Range<i64>),
+    List(std::vec::IntoIter<i64>),
+}
+
+impl SelectedIds {
+    /// Read the ids out of a raw vertex or edge iterator.
+    ///
+    /// # Safety
+    /// `vec` must be valid if `is_range` is false.
+    unsafe fn new(is_range: bool, start: i64, end: i64, vec: *const igraph_vector_int_t) -> Self {
+        if is_range {
+            SelectedIds::Range(start..end)
+        } else {
+            // Copied, since the vector may be freed with the raw iterator.
+            let ids = unsafe { vector_int_slice(vec) }[start as usize..end as usize].to_vec();
+            SelectedIds::List(ids.into_iter())
+        }
+    }
+}
+
+impl Iterator for SelectedIds {
+    type Item = i64;
+
+    fn next(&mut self) -> Option<i64> {
+        match self {
+            SelectedIds::Range(r) => r.next(),
+            SelectedIds::List(l) => l.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = match self {
+            SelectedIds::Range(r) => (r.end - r.start).max(0) as usize,
+            SelectedIds::List(l) => l.len(),
+        };
+        (n, Some(n))
+    }
+}
+
+/// Iterator over vertex ids, created by [`Graph::vertices`] and
+/// [`Graph::vertices_in`].
+pub struct VertexIter {
+    ids: SelectedIds,
+}
+
+impl Iterator for VertexIter {
+    type Item = i64;
+
+    fn next(&mut self) -> Option<i64> {
+        self.ids.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ids.size_hint()
+    }
+}
+
+impl ExactSizeIterator for VertexIter {}
+
+impl FusedIterator for VertexIter {}
+
+/// Iterator over edges as `(eid, from, to)`, created by [`Graph::edges`]
+/// and [`Graph::edges_in`].
+///
+/// Endpoints are read straight from the graph's edge storage rather than
+/// through one FFI call per edge.
+pub struct EdgeIter<'a> {
+    ids: SelectedIds,
+    from: &'a [i64],
+    to: &'a [i64],
+    directed: bool,
+}
+
+impl Iterator for EdgeIter<'_> {
+    type Item = (i64, i64, i64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let eid = self.ids.next()?;
+        let (from, to) = (self.from[eid as usize], self.to[eid as usize]);
+        // Undirected edges are stored with the larger id first; report them
+        // the same way igraph_edge() does.
+        if self.directed {
+            Some((eid, from, to))
+        } else {
+            Some((eid, to, from))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ids.size_hint()
+    }
+}
+
+impl ExactSizeIterator for EdgeIter<'_> {}
+
+impl FusedIterator for EdgeIter<'_> {}
+
+impl Graph {
+    /// Iterate over all vertex ids.
+    pub fn vertices(&self) -> Result<VertexIter> {
+        self.vertices_in(VertexSelector::All)
+    }
+
+    /// Iterate over the ids of the selected vertices.
+    pub fn vertices_in(&self, vids: VertexSelector<'_>) -> Result<VertexIter> {
+        vids.with_raw(|vs| {
+            let mut vit = MaybeUninit::<igraph_vit_t>::uninit();
+            unsafe {
+                check(igraph_vit_create(self.as_ptr(), vs, vit.as_mut_ptr()))?;
+                let vit = vit.assume_init();
+                let ids = SelectedIds::new(
+                    vit.type_ == igraph_vit_type_t_IGRAPH_VIT_RANGE,
+                    vit.start,
+                    vit.end,
+                    vit.vec,
+                );
+                igraph_vit_destroy(&vit);
+                Ok(VertexIter { ids })
+            }
+        })
+    }
+
+    /// Iterate over all edges as `(eid, from, to)`.
+    ///
+    /// To look up the endpoints of a batch of edge ids in one call, use
+    /// [`Graph::edge_endpoints`].
+    pub fn edges(&self) -> Result<EdgeIter<'_>> {
+        self.edges_in(EdgeSelector::All)
+    }
+
+    /// Iterate over the selected edges as `(eid, from, to)`.
+    pub fn edges_in(&self, eids: EdgeSelector<'_>) -> Result<EdgeIter<'_>> {
+        let ids = eids.with_raw(|es| {
+            let mut eit = MaybeUninit::<igraph_eit_t>::uninit();
+            unsafe {
+                check(igraph_eit_create(self.as_ptr(), es, eit.as_mut_ptr()))?;
+                let eit = eit.assume_init();
+                let ids = SelectedIds::new(
+                    eit.type_ == igraph_eit_type_t_IGRAPH_EIT_RANGE,
+                    eit.start,
+                    eit.end,
+                    eit.vec,
+                );
+                igraph_eit_destroy(&eit);
+                Ok(ids)
+            }
+        })?;
+        let (from, to) = unsafe {
+            (
+                vector_int_slice(&self.inner.from),
+                vector_int_slice(&self.inner.to),
+            )
+        };
+        Ok(EdgeIter {
+            ids,
+            from,
+            to,
+            directed: self.is_directed(),
+        })
+    }
+}
@@ -0,0 +1,136 @@
+use igraph_sys::*;
+
+use super::Graph;
+use crate::error::{Error, Result, check};
+use crate::matrix::Matrix;
+use crate::rng::SeededRng;
+use crate::selector::VertexSelector;
+use crate::types::NeighborMode;
+use crate::vector::{Vector, VectorInt};
+
+// Layouts return one row of coordinates per vertex.
+impl Graph {
+    /// Place the vertices uniformly at random in the square `[-1, 1]^2`.
+    pub fn layout_random(&self, seed: Option<u64>) -> Result<Matrix> {
+        let _rng = seed.map(SeededRng::new).transpose()?;
+        let mut res = Matrix::new(0, 0)?;
+        unsafe {
+            check(igraph_layout_random(self.as_ptr(), res.as_mut_ptr()))?;
+        }
+        Ok(res)
+    }
+
+    /// Place the selected vertices evenly on the unit circle, in selection
+    /// order. Vertices not selected are placed at the origin.
+    pub fn layout_circle(&self, order: VertexSelector<'_>) -> Result<Matrix> {
+        let mut res = Matrix::new(0, 0)?;
+        order.with_raw(|vs| unsafe {
+            check(igraph_layout_circle(self.as_ptr(), res.as_mut_ptr(), vs))
+        })?;
+        Ok(res)
+    }
+
+    /// Place `center` at the origin and the other vertices on the unit
+    /// circle around it, in the order given by `order` (which must list
+    /// every vertex) or by vertex id.
+    pub fn layout_star(&self, center: i64, order: Option<&[i64]>) -> Result<Matrix> {
+        if self.vcount() > 0 && !(0..self.vcount()).contains(&center) {
+            return Err(Error::InvalidVertexId);
+        }
+        if order.is_some_and(|o| o.len() as i64 != self.vcount()) {
+            return Err(Error::InvalidValue);
+        }
+        let order = order.map(VectorInt::from_slice).transpose()?;
+        let mut res = Matrix::new(0, 0)?;
+        unsafe {
+            check(igraph_layout_star(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                center,
+                order.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+            ))?;
+        }
+        Ok(res)
+    }
+
+    /// Place the vertices on a grid `width` columns wide, filled row by
+    /// row. Without `width`, a roughly square grid is used.
+    pub fn layout_grid(&self, width: Option<i64>) -> Result<Matrix> {
+        let mut res = Matrix::new(0, 0)?;
+        unsafe {
+            check(igraph_layout_grid(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                width.unwrap_or(0),
+            ))?;
+        }
+        Ok(res)
+    }
+
+    /// Place the vertices evenly on the surface of the unit sphere, giving
+    /// three coordinates per vertex.
+    pub fn layout_sphere(&self) -> Result<Matrix> {
+        let mut res = Matrix::new(0, 0)?;
+        unsafe {
+            check(igraph_layout_sphere(self.as_ptr(), res.as_mut_ptr()))?;
+        }
+        Ok(res)
+    }
+
+    /// Compute a force-directed layout with the Fruchterman-Reingold
+    /// algorithm, starting from a random placement.
+    ///
+    /// Edge `weights` strengthen attraction along heavier edges.
+    pub fn layout_fruchterman_reingold(
+        &self,
+        niter: i64,
+        weights: Option<&[f64]>,
+        seed: Option<u64>,
+    ) -> Result<Matrix> {
+        if niter < 0 {
+            return Err(Error::InvalidValue);
+        }
+        let _rng = seed.map(SeededRng::new).transpose()?;
+        let w = weights.map(Vector::from_slice).transpose()?;
+        let mut res = Matrix::new(0, 0)?;
+        unsafe {
+            check(igraph_layout_fruchterman_reingold(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                false, // use_seed
+                niter,
+                (self.vcount() as f64).sqrt() / 10.0, // start_temp
+                igraph_layout_grid_t_IGRAPH_LAYOUT_AUTOGRID,
+                w.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                std::ptr::null(), // minx
+                std::ptr::null(), // maxx
+                std::ptr::null(), // miny
+                std::ptr::null(), // maxy
+            ))?;
+        }
+        Ok(res)
+    }
+
+    /// Compute a tree layout with the Reingold-Tilford algorithm, placing
+    /// each vertex one unit below its parent.
+    ///
+    /// Without `roots`, a root is chosen in each component.
+    pub fn layout_reingold_tilford(
+        &self,
+        mode: NeighborMode,
+        roots: Option<&[i64]>,
+    ) -> Result<Matrix> {
+        let roots = roots.map(VectorInt::from_slice).transpose()?;
+        let mut res = Matrix::new(0, 0)?;
+        unsafe {
+            check(igraph_layout_reingold_tilford(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                mode.to_raw(),
+                roots.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                std::ptr::null(), // rootlevel
+            ))?;
+        }
+        Ok(res)
+    }
+}
@@ -1,12 +1,28 @@
+mod adjacency;
 mod centrality;
+mod cliques;
 mod community;
 mod components;
 mod constructors;
+mod cores;
+mod cycles;
+mod dag;
+mod eulerian;
 mod isomorphism;
+mod iterators;
+mod layout;
+mod motifs;
 mod mutation;
+mod neighborhood;
 mod paths;
 mod query;
+mod spanning;
 mod transform;
+mod transitivity;
+mod traversal;
+
+pub use iterators::{EdgeIter, VertexIter};
+pub use traversal::{BfsIter, DfsIter};
 
 use std::mem::MaybeUninit;
 
@@ -61,3 +77,40 @@ impl Drop for Graph {
 // `igraph_t` contains an internal property cache that may be mutated by
 // seemingly read-only C functions, making concurrent `&Graph` access unsafe.
 unsafe impl Send for Graph {}
+
+/// Owned `igraph_graph_list_t`, used to receive lists of graphs from igraph.
+pub(crate) struct GraphList {
+    inner: igraph_graph_list_t,
+}
+
+impl GraphList {
+    pub(crate) fn new() -> crate::error::Result<Self> {
+        let mut v = MaybeUninit::uninit();
+        unsafe {
+            crate::error::check(igraph_graph_list_init(v.as_mut_ptr(), 0))?;
+            Ok(Self {
+                inner: v.assume_init(),
+            })
+        }
+    }
+
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut igraph_graph_list_t {
+        &mut self.inner
+    }
+
+    /// Move the graphs out of the list, preserving their order.
+    pub(crate) fn into_vec(mut self) -> Vec<Graph> {
+        let n = unsafe { igraph_graph_list_size(&self.inner) };
+        let mut graphs: Vec<Graph> = (0..n)
+            .map(|_| unsafe { Graph::from_raw(igraph_graph_list_pop_back(&mut self.inner)) })
+            .collect();
+        graphs.reverse();
+        graphs
+    }
+}
+
+impl Drop for GraphList {
+    fn drop(&mut self) {
+        unsafe { igraph_graph_list_destroy(&mut self.inner) }
+    }
+}
@@ -0,0 +1,172 @@
+use std::ops::ControlFlow;
+use std::os::raw::c_void;
+
+use igraph_sys::*;
+
+use super::Graph;
+use crate::callback::PanicSlot;
+use crate::error::{Result, check};
+use crate::rng::SeededRng;
+use crate::types::{DyadCensus, TriadCensus};
+use crate::vector::{Vector, VectorInt, vector_int_slice};
+
+/// State shared with the motif callback trampoline through igraph's `extra` pointer.
+struct MotifContext<'a> {
+    handler: &'a mut dyn FnMut(&[i64], i64) -> ControlFlow<()>,
+    panic: PanicSlot,
+}
+
+unsafe extern "C" fn motif_trampoline(
+    _graph: *const igraph_t,
+    vids: *const igraph_vector_int_t,
+    isoclass: igraph_int_t,
+    extra: *mut c_void,
+) -> igraph_error_t {
+    let ctx = unsafe { &mut *extra.cast::<MotifContext>() };
+    let vids = unsafe { vector_int_slice(vids) };
+    let handler = &mut ctx.handler;
+    ctx.panic.call_handler(|| handler(vids, isoclass))
+}
+
+impl Graph {
+    /// Count motifs of `size` vertices using the RAND-ESU algorithm.
+    ///
+    /// Returns a histogram indexed by isoclass (see [`Graph::isoclass`]).
+    /// Entries for classes that are not connected are NaN, as these are not
+    /// considered motifs. `cut_prob`, if given, holds one probability per
+    /// level of the search tree with which that branch is skipped; `seed`
+    /// makes this sampling reproducible.
+    pub fn motifs_randesu(
+        &self,
+        size: i64,
+        cut_prob: Option<&[f64]>,
+        seed: Option<u64>,
+    ) -> Result<Vec<f64>> {
+        let cut_prob = cut_prob.map(Vector::from_slice).transpose()?;
+        let mut hist = Vector::new()?;
+        let _rng = seed.map(SeededRng::new).transpose()?;
+        unsafe {
+            check(igraph_motifs_randesu(
+                self.as_ptr(),
+                hist.as_mut_ptr(),
+                size,
+                cut_prob.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+            ))?;
+        }
+        Ok(hist.to_vec())
+    }
+
+    /// Stream the motifs of `size` vertices found by RAND-ESU.
+    ///
+    /// `handler` is called with the vertex ids and the isoclass of each motif
+    /// occurrence as it is found; returning [`ControlFlow::Break`] stops the
+    /// search. See [`Graph::motifs_randesu`] for `cut_prob` and `seed`.
+    pub fn motifs_randesu_callback(
+        &self,
+        size: i64,
+        cut_prob: Option<&[f64]>,
+        seed: Option<u64>,
+        mut handler: impl FnMut(&[i64], i64) -> ControlFlow<()>,
+    ) -> Result<()> {
+        let cut_prob = cut_prob.map(Vector::from_slice).transpose()?;
+        let mut ctx = MotifContext {
+            handler: &mut handler,
+            panic: PanicSlot::default(),
+        };
+        let _rng = seed.map(SeededRng::new).transpose()?;
+        let code = unsafe {
+            igraph_motifs_randesu_callback(
+                self.as_ptr(),
+                size,
+                cut_prob.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                Some(motif_trampoline),
+                (&mut ctx as *mut MotifContext).cast(),
+            )
+        };
+        ctx.panic.resume();
+        check(code)
+    }
+
+    /// Estimate the total number of motifs of `size` vertices.
+    ///
+    /// The search is started from `sample` if given, otherwise from
+    /// `sample_size` vertices chosen at random.
+    pub fn motifs_randesu_estimate(
+        &self,
+        size: i64,
+        cut_prob: Option<&[f64]>,
+        sample_size: i64,
+        sample: Option<&[i64]>,
+        seed: Option<u64>,
+    ) -> Result<f64> {
+        let cut_prob = cut_prob.map(Vector::from_slice).transpose()?;
+        let sample = sample.map(VectorInt::from_slice).transpose()?;
+        let mut est: f64 = 0.0;
+        let _rng = seed.map(SeededRng::new).transpose()?;
+        unsafe {
+            check(igraph_motifs_randesu_estimate(
+                self.as_ptr(),
+                &mut est,
+                size,
+                cut_prob.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                sample_size,
+                sample.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+            ))?;
+        }
+        Ok(est)
+    }
+
+    /// Count the total number of motifs of `size` vertices.
+    ///
+    /// Cheaper than [`Graph::motifs_randesu`] as motifs are not classified.
+    pub fn motifs_randesu_no(
+        &self,
+        size: i64,
+        cut_prob: Option<&[f64]>,
+        seed: Option<u64>,
+    ) -> Result<f64> {
+        let cut_prob = cut_prob.map(Vector::from_slice).transpose()?;
+        let mut no: f64 = 0.0;
+        let _rng = seed.map(SeededRng::new).transpose()?;
+        unsafe {
+            check(igraph_motifs_randesu_no(
+                self.as_ptr(),
+                &mut no,
+                size,
+                cut_prob.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+            ))?;
+        }
+        Ok(no)
+    }
+
+    /// Compute the dyad census of the graph.
+    ///
+    /// Undirected graphs are treated as if every edge was mutual.
+    pub fn dyad_census(&self) -> Result<DyadCensus> {
+        let mut census = DyadCensus {
+            mutual: 0.0,
+            asymmetric: 0.0,
+            null: 0.0,
+        };
+        unsafe {
+            check(igraph_dyad_census(
+                self.as_ptr(),
+                &mut census.mutual,
+                &mut census.asymmetric,
+                &mut census.null,
+            ))?;
+        }
+        Ok(census)
+    }
+
+    /// Compute the triad census of the graph.
+    ///
+    /// Undirected graphs are treated as if every edge was mutual.
+    pub fn triad_census(&self) -> Result<TriadCensus> {
+        let mut res = Vector::new()?;
+        unsafe {
+            check(igraph_triad_census(self.as_ptr(), res.as_mut_ptr()))?;
+        }
+        Ok(TriadCensus::from_slice(&res.to_vec()))
+    }
+}
@@ -0,0 +1,289 @@
+use igraph_sys::*;
+
+use super::{Graph, GraphList};
+use crate::error::{Result, check};
+use crate::selector::VertexSelector;
+use crate::types::NeighborMode;
+use crate::vector::{Vector, VectorInt, VectorIntList};
+
+impl Graph {
+    /// Find the vertices within `order` steps of each selected vertex.
+    ///
+    /// Vertices closer than `mindist` are left out, so `mindist = 1` excludes
+    /// the center vertex itself. Each neighborhood starts with its center
+    /// (unless excluded) followed by the others in breadth-first order.
+    pub fn neighborhood(
+        &self,
+        vids: VertexSelector<'_>,
+        order: i64,
+        mode: NeighborMode,
+        mindist: i64,
+    ) -> Result<Vec<Vec<i64>>> {
+        let mut res = VectorIntList::new()?;
+        vids.with_raw(|vs| unsafe {
+            check(igraph_neighborhood(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                vs,
+                order,
+                mode.to_raw(),
+                mindist,
+            ))
+        })?;
+        Ok(res.to_vec_of_vecs())
+    }
+
+    /// Count the vertices in each neighborhood returned by
+    /// [`Graph::neighborhood`].
+    pub fn neighborhood_size(
+        &self,
+        vids: VertexSelector<'_>,
+        order: i64,
+        mode: NeighborMode,
+        mindist: i64,
+    ) -> Result<Vec<i64>> {
+        let mut res = VectorInt::new()?;
+        vids.with_raw(|vs| unsafe {
+            check(igraph_neighborhood_size(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                vs,
+                order,
+                mode.to_raw(),
+                mindist,
+            ))
+        })?;
+        Ok(res.to_vec())
+    }
+
+    /// Extract the subgraph induced by each neighborhood returned by
+    /// [`Graph::neighborhood`] (the ego networks).
+    ///
+    /// Each subgraph comes with, for each of its vertices, the id of the
+    /// corresponding vertex in this graph. Subgraph vertices keep the order
+    /// of their original ids, so the center is not necessarily vertex 0.
+    pub fn neighborhood_graphs(
+        &self,
+        vids: VertexSelector<'_>,
+        order: i64,
+        mode: NeighborMode,
+        mindist: i64,
+    ) -> Result<Vec<(Graph, Vec<i64>)>> {
+        let mut res = GraphList::new()?;
+        vids.with_raw(|vs| unsafe {
+            check(igraph_neighborhood_graphs(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                vs,
+                order,
+                mode.to_raw(),
+                mindist,
+            ))
+        })?;
+        let maps = self
+            .neighborhood(vids, order, mode, mindist)?
+            .into_iter()
+            .map(|mut vertices| {
+                // igraph renumbers induced subgraph vertices by original id.
+                vertices.sort_unstable();
+                vertices
+            });
+        Ok(res.into_vec().into_iter().zip(maps).collect())
+    }
+
+    /// Connect every vertex to all vertices within `order` steps of it.
+    ///
+    /// Existing edges are kept, so the result may have multi-edges if the
+    /// graph already did.
+    pub fn connect_neighborhood(&mut self, order: i64, mode: NeighborMode) -> Result<()> {
+        unsafe {
+            check(igraph_connect_neighborhood(
+                self.as_mut_ptr(),
+                order,
+                mode.to_raw(),
+            ))
+        }
+    }
+
+    /// Compute the `order`-th power of the graph, connecting all vertex
+    /// pairs within `order` steps of each other.
+    ///
+    /// For directed graphs, `directed` selects whether edge directions are
+    /// followed.
+    pub fn graph_power(&self, order: i64, directed: bool) -> Result<Graph> {
+        Graph::init_with(|res| unsafe { igraph_graph_power(self.as_ptr(), res, order, directed) })
+    }
+
+    /// Compute the local scan statistic of order 0: the (weighted) degree
+    /// of every vertex.
+    pub fn local_scan_0(&self, weights: Option<&[f64]>, mode: NeighborMode) -> Result<Vec<f64>> {
+        let w = weights.map(Vector::from_slice).transpose()?;
+        let mut res = Vector::new()?;
+        unsafe {
+            check(igraph_local_scan_0(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                w.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                mode.to_raw(),
+            ))?;
+        }
+        Ok(res.to_vec())
+    }
+
+    /// Compute the order 0 scan statistic of `them` over the neighborhoods
+    /// of this graph: the (weighted) number of edges of `them` between each
+    /// vertex and its neighbors in this graph.
+    pub fn local_scan_0_them(
+        &self,
+        them: &Graph,
+        weights_them: Option<&[f64]>,
+        mode: NeighborMode,
+    ) -> Result<Vec<f64>> {
+        let w = weights_them.map(Vector::from_slice).transpose()?;
+        let mut res = Vector::new()?;
+        unsafe {
+            check(igraph_local_scan_0_them(
+                self.as_ptr(),
+                them.as_ptr(),
+                res.as_mut_ptr(),
+                w.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                mode.to_raw(),
+            ))?;
+        }
+        Ok(res.to_vec())
+    }
+
+    /// Compute the local scan statistic of order 1: the (weighted) number
+    /// of edges among each vertex and its neighbors.
+    pub fn local_scan_1_ecount(
+        &self,
+        weights: Option<&[f64]>,
+        mode: NeighborMode,
+    ) -> Result<Vec<f64>> {
+        let w = weights.map(Vector::from_slice).transpose()?;
+        let mut res = Vector::new()?;
+        unsafe {
+            check(igraph_local_scan_1_ecount(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                w.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                mode.to_raw(),
+            ))?;
+        }
+        Ok(res.to_vec())
+    }
+
+    /// Compute the order 1 scan statistic of `them`, counting its edges
+    /// within the 1-neighborhoods of this graph.
+    pub fn local_scan_1_ecount_them(
+        &self,
+        them: &Graph,
+        weights_them: Option<&[f64]>,
+        mode: NeighborMode,
+    ) -> Result<Vec<f64>> {
+        let w = weights_them.map(Vector::from_slice).transpose()?;
+        let mut res = Vector::new()?;
+        unsafe {
+            check(igraph_local_scan_1_ecount_them(
+                self.as_ptr(),
+                them.as_ptr(),
+                res.as_mut_ptr(),
+                w.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                mode.to_raw(),
+            ))?;
+        }
+        Ok(res.to_vec())
+    }
+
+    /// Compute the local scan statistic of order `k`: the (weighted) number
+    /// of edges within `k` steps of each vertex.
+    pub fn local_scan_k_ecount(
+        &self,
+        k: i64,
+        weights: Option<&[f64]>,
+        mode: NeighborMode,
+    ) -> Result<Vec<f64>> {
+        let w = weights.map(Vector::from_slice).transpose()?;
+        let mut res = Vector::new()?;
+        unsafe {
+            check(igraph_local_scan_k_ecount(
+                self.as_ptr(),
+                k,
+                res.as_mut_ptr(),
+                w.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                mode.to_raw(),
+            ))?;
+        }
+        Ok(res.to_vec())
+    }
+
+    /// Compute the order `k` scan statistic of `them`, counting its edges
+    /// within the `k`-neighborhoods of this graph.
+    pub fn local_scan_k_ecount_them(
+        &self,
+        them: &Graph,
+        k: i64,
+        weights_them: Option<&[f64]>,
+        mode: NeighborMode,
+    ) -> Result<Vec<f64>> {
+        let w = weights_them.map(Vector::from_slice).transpose()?;
+        let mut res = Vector::new()?;
+        unsafe {
+            check(igraph_local_scan_k_ecount_them(
+                self.as_ptr(),
+                them.as_ptr(),
+                k,
+                res.as_mut_ptr(),
+                w.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                mode.to_raw(),
+            ))?;
+        }
+        Ok(res.to_vec())
+    }
+
+    /// Count the (weighted) edges within a given neighborhood of each vertex.
+    ///
+    /// `neighborhoods` holds one vertex list per vertex; edges are counted if
+    /// they join the vertex or any of its listed neighbors to each other.
+    pub fn local_scan_neighborhood_ecount(
+        &self,
+        weights: Option<&[f64]>,
+        neighborhoods: &[Vec<i64>],
+    ) -> Result<Vec<f64>> {
+        let w = weights.map(Vector::from_slice).transpose()?;
+        let n = VectorIntList::from_slices(neighborhoods)?;
+        let mut res = Vector::new()?;
+        unsafe {
+            check(igraph_local_scan_neighborhood_ecount(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                w.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                n.as_ptr(),
+            ))?;
+        }
+        Ok(res.to_vec())
+    }
+
+    /// Count the (weighted) edges within each of the given vertex subsets.
+    ///
+    /// Unlike [`Graph::local_scan_neighborhood_ecount`], the subsets need not
+    /// correspond to vertices, and only edges between their members count.
+    pub fn local_scan_subset_ecount(
+        &self,
+        weights: Option<&[f64]>,
+        subsets: &[Vec<i64>],
+    ) -> Result<Vec<f64>> {
+        let w = weights.map(Vector::from_slice).transpose()?;
+        let s = VectorIntList::from_slices(subsets)?;
+        let mut res = Vector::new()?;
+        unsafe {
+            check(igraph_local_scan_subset_ecount(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                w.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                s.as_ptr(),
+            ))?;
+        }
+        Ok(res.to_vec())
+    }
+}
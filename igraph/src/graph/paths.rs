@@ -3,26 +3,34 @@ use igraph_sys::*;
 use super::Graph;
 use crate::error::{Result, check};
 use crate::matrix::Matrix;
+use crate::selector::VertexSelector;
 use crate::types::NeighborMode;
 
 impl Graph {
-    /// Compute shortest path distances between all vertex pairs.
+    /// Compute shortest path distances between the selected vertices.
     ///
-    /// Returns a matrix where element `[i][j]` is the distance from vertex `i` to vertex `j`.
-    pub fn distances(&self, mode: NeighborMode) -> Result<Vec<Vec<f64>>> {
-        let n = self.vcount();
-        let mut res = Matrix::new(n, n)?;
-        unsafe {
-            check(igraph_distances(
-                self.as_ptr(),
-                std::ptr::null(), // weights
-                res.as_mut_ptr(),
-                igraph_vss_all(),
-                igraph_vss_all(),
-                mode.to_raw(),
-            ))?;
-        }
-        Ok(res.to_vec_of_vecs())
+    /// Returns a matrix where element `(i, j)` is the distance from the
+    /// `i`-th vertex of `from` to the `j`-th vertex of `to`.
+    pub fn distances(
+        &self,
+        from: VertexSelector<'_>,
+        to: VertexSelector<'_>,
+        mode: NeighborMode,
+    ) -> Result<Matrix> {
+        let mut res = Matrix::new(0, 0)?;
+        from.with_raw(|from| {
+            to.with_raw(|to| unsafe {
+                check(igraph_distances(
+                    self.as_ptr(),
+                    std::ptr::null(), // weights
+                    res.as_mut_ptr(),
+                    from,
+                    to,
+                    mode.to_raw(),
+                ))
+            })
+        })?;
+        Ok(res)
     }
 
     /// Compute the diameter (longest shortest path) of the graph.
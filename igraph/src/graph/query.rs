@@ -1,9 +1,10 @@
 use igraph_sys::*;
 
 use super::Graph;
-use crate::error::{Result, check};
-use crate::types::{Loops, NeighborMode};
-use crate::vector::VectorInt;
+use crate::error::{Error, Result, check};
+use crate::selector::{EdgeSelector, VertexSelector};
+use crate::types::{Loops, NeighborMode, ReciprocityMode};
+use crate::vector::{Vector, VectorBool, VectorInt};
 
 impl Graph {
     /// Return the number of vertices.
@@ -37,18 +38,40 @@ impl Graph {
         Ok(neis.to_vec())
     }
 
-    /// Return the degree of all vertices.
-    pub fn degree(&self, mode: NeighborMode, loops: Loops) -> Result<Vec<i64>> {
-        let mut res = VectorInt::new()?;
+    /// Return the ids of the edges incident to vertex `vid`.
+    ///
+    /// `loops` controls how often self-loops are listed.
+    pub fn incident(&self, vid: i64, mode: NeighborMode, loops: Loops) -> Result<Vec<i64>> {
+        let mut eids = VectorInt::new()?;
         unsafe {
-            check(igraph_degree(
+            check(igraph_incident(
                 self.as_ptr(),
-                res.as_mut_ptr(),
-                igraph_vss_all(),
+                eids.as_mut_ptr(),
+                vid,
                 mode.to_raw(),
                 loops.to_raw(),
             ))?;
         }
+        Ok(eids.to_vec())
+    }
+
+    /// Return the degree of the selected vertices.
+    pub fn degree(
+        &self,
+        vids: VertexSelector<'_>,
+        mode: NeighborMode,
+        loops: Loops,
+    ) -> Result<Vec<i64>> {
+        let mut res = VectorInt::new()?;
+        vids.with_raw(|vs| unsafe {
+            check(igraph_degree(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                vs,
+                mode.to_raw(),
+                loops.to_raw(),
+            ))
+        })?;
         Ok(res.to_vec())
     }
 
@@ -80,4 +103,356 @@ impl Graph {
         let flat = ev.to_vec();
         Ok(flat.chunks_exact(2).map(|c| (c[0], c[1])).collect())
     }
+
+    /// Return the endpoints of the selected edges as `(from, to)` pairs.
+    ///
+    /// This is the bulk counterpart of [`Graph::edge`]; [`Graph::edges`]
+    /// iterates over edges lazily instead.
+    pub fn edge_endpoints(&self, eids: EdgeSelector<'_>) -> Result<Vec<(i64, i64)>> {
+        let mut ev = VectorInt::new()?;
+        eids.with_raw(|es| unsafe {
+            check(igraph_edges(
+                self.as_ptr(),
+                es,
+                ev.as_mut_ptr(),
+                false, // bycol
+            ))
+        })?;
+        let flat = ev.to_vec();
+        Ok(flat.chunks_exact(2).map(|c| (c[0], c[1])).collect())
+    }
+
+    /// Look up the id of an edge from `from` to `to`.
+    ///
+    /// In directed graphs, `directed = false` also accepts an edge from `to`
+    /// to `from`. A missing edge gives `None`, or [`Error::InvalidValue`] if
+    /// `error_if_missing` is set.
+    pub fn get_eid(
+        &self,
+        from: i64,
+        to: i64,
+        directed: bool,
+        error_if_missing: bool,
+    ) -> Result<Option<i64>> {
+        let mut eid: i64 = -1;
+        unsafe {
+            check(igraph_get_eid(
+                self.as_ptr(),
+                &mut eid,
+                from,
+                to,
+                directed,
+                false, // error, reported below instead
+            ))?;
+        }
+        match eid {
+            -1 if error_if_missing => Err(Error::InvalidValue),
+            -1 => Ok(None),
+            eid => Ok(Some(eid)),
+        }
+    }
+
+    /// Look up the edge ids for many vertex pairs at once.
+    ///
+    /// Behaves like [`Graph::get_eid`] for each pair; with
+    /// `error_if_missing`, a single missing edge fails the whole lookup.
+    pub fn get_eids(
+        &self,
+        pairs: &[(i64, i64)],
+        directed: bool,
+        error_if_missing: bool,
+    ) -> Result<Vec<Option<i64>>> {
+        let flat: Vec<i64> = pairs.iter().flat_map(|&(from, to)| [from, to]).collect();
+        let p = VectorInt::from_slice(&flat)?;
+        let mut eids = VectorInt::new()?;
+        unsafe {
+            check(igraph_get_eids(
+                self.as_ptr(),
+                eids.as_mut_ptr(),
+                p.as_ptr(),
+                directed,
+                false, // error, reported below instead
+            ))?;
+        }
+        let eids: Vec<Option<i64>> = eids
+            .to_vec()
+            .into_iter()
+            .map(|e| (e >= 0).then_some(e))
+            .collect();
+        if error_if_missing && eids.contains(&None) {
+            return Err(Error::InvalidValue);
+        }
+        Ok(eids)
+    }
+
+    /// Return the ids of all edges between `source` and `target`, including
+    /// multi-edges.
+    ///
+    /// In directed graphs, `directed = false` also includes edges from
+    /// `target` to `source`.
+    pub fn get_all_eids_between(
+        &self,
+        source: i64,
+        target: i64,
+        directed: bool,
+    ) -> Result<Vec<i64>> {
+        let mut eids = VectorInt::new()?;
+        unsafe {
+            check(igraph_get_all_eids_between(
+                self.as_ptr(),
+                eids.as_mut_ptr(),
+                source,
+                target,
+                directed,
+            ))?;
+        }
+        Ok(eids.to_vec())
+    }
+
+    /// Return whether the graph has neither self-loops nor multi-edges.
+    ///
+    /// With `directed = false`, a directed graph is also not simple if it
+    /// has a mutual pair of edges.
+    pub fn is_simple(&self, directed: bool) -> Result<bool> {
+        let mut res: bool = false;
+        unsafe {
+            check(igraph_is_simple(self.as_ptr(), &mut res, directed))?;
+        }
+        Ok(res)
+    }
+
+    /// Return whether the graph has at least one self-loop.
+    pub fn has_loop(&self) -> Result<bool> {
+        let mut res: bool = false;
+        unsafe {
+            check(igraph_has_loop(self.as_ptr(), &mut res))?;
+        }
+        Ok(res)
+    }
+
+    /// Return whether the graph has at least one multi-edge.
+    pub fn has_multiple(&self) -> Result<bool> {
+        let mut res: bool = false;
+        unsafe {
+            check(igraph_has_multiple(self.as_ptr(), &mut res))?;
+        }
+        Ok(res)
+    }
+
+    /// Return whether a directed graph has a pair of edges in opposite
+    /// directions. With `loops`, a self-loop counts as mutual.
+    pub fn has_mutual(&self, loops: bool) -> Result<bool> {
+        let mut res: bool = false;
+        unsafe {
+            check(igraph_has_mutual(self.as_ptr(), &mut res, loops))?;
+        }
+        Ok(res)
+    }
+
+    /// Return whether every pair of distinct vertices is connected.
+    pub fn is_complete(&self) -> Result<bool> {
+        let mut res: bool = false;
+        unsafe {
+            check(igraph_is_complete(self.as_ptr(), &mut res))?;
+        }
+        Ok(res)
+    }
+
+    /// Return whether `candidate` is a clique.
+    ///
+    /// In directed graphs, `directed` requires edges in both directions
+    /// between every pair.
+    pub fn is_clique(&self, candidate: &[i64], directed: bool) -> Result<bool> {
+        let c = VectorInt::from_slice(candidate)?;
+        let mut res: bool = false;
+        unsafe {
+            check(igraph_is_clique(
+                self.as_ptr(),
+                igraph_vss_vector(c.as_ptr()),
+                directed,
+                &mut res,
+            ))?;
+        }
+        Ok(res)
+    }
+
+    /// Return whether no two vertices of `candidate` are adjacent.
+    pub fn is_independent_vertex_set(&self, candidate: &[i64]) -> Result<bool> {
+        let c = VectorInt::from_slice(candidate)?;
+        let mut res: bool = false;
+        unsafe {
+            check(igraph_is_independent_vertex_set(
+                self.as_ptr(),
+                igraph_vss_vector(c.as_ptr()),
+                &mut res,
+            ))?;
+        }
+        Ok(res)
+    }
+
+    /// Run maximum cardinality search.
+    ///
+    /// Returns `(alpha, alpham1)`: `alpha[v]` is the rank of vertex `v` and
+    /// `alpham1` its inverse, listing the vertices by rank. Visiting them
+    /// in reverse rank order yields a perfect elimination ordering if the
+    /// graph is chordal.
+    pub fn maximum_cardinality_search(&self) -> Result<(Vec<i64>, Vec<i64>)> {
+        let mut alpha = VectorInt::new()?;
+        let mut alpham1 = VectorInt::new()?;
+        unsafe {
+            check(igraph_maximum_cardinality_search(
+                self.as_ptr(),
+                alpha.as_mut_ptr(),
+                alpham1.as_mut_ptr(),
+            ))?;
+        }
+        Ok((alpha.to_vec(), alpham1.to_vec()))
+    }
+
+    /// Return whether the graph is chordal, i.e. every cycle of four or more
+    /// vertices has a chord. Edge directions are ignored.
+    pub fn is_chordal(&self) -> Result<bool> {
+        let mut res: bool = false;
+        unsafe {
+            check(igraph_is_chordal(
+                self.as_ptr(),
+                std::ptr::null(), // alpha
+                std::ptr::null(), // alpham1
+                &mut res,
+                std::ptr::null_mut(), // fill_in
+                std::ptr::null_mut(), // newgraph
+            ))?;
+        }
+        Ok(res)
+    }
+
+    /// Return whether the graph is perfect, i.e. the chromatic number of
+    /// every induced subgraph equals its clique number.
+    ///
+    /// Only simple undirected graphs are supported; others give
+    /// `Error::InvalidValue`.
+    pub fn is_perfect(&self) -> Result<bool> {
+        if self.is_directed() || !self.is_simple(true)? {
+            return Err(Error::InvalidValue);
+        }
+        let mut res: bool = false;
+        unsafe {
+            check(igraph_is_perfect(self.as_ptr(), &mut res))?;
+        }
+        Ok(res)
+    }
+
+    /// Return, for each selected edge, whether it is a self-loop.
+    pub fn is_loop(&self, eids: EdgeSelector<'_>) -> Result<Vec<bool>> {
+        let mut res = VectorBool::new()?;
+        eids.with_raw(|es| unsafe { check(igraph_is_loop(self.as_ptr(), res.as_mut_ptr(), es)) })?;
+        Ok(res.to_vec())
+    }
+
+    /// Return, for each selected edge, whether it repeats an edge with a
+    /// lower id between the same endpoints.
+    ///
+    /// The first edge of each group of multi-edges is not marked.
+    pub fn is_multiple(&self, eids: EdgeSelector<'_>) -> Result<Vec<bool>> {
+        let mut res = VectorBool::new()?;
+        eids.with_raw(|es| unsafe {
+            check(igraph_is_multiple(self.as_ptr(), res.as_mut_ptr(), es))
+        })?;
+        Ok(res.to_vec())
+    }
+
+    /// Return, for each selected edge, how many edges join the same
+    /// endpoints, including itself.
+    pub fn count_multiple(&self, eids: EdgeSelector<'_>) -> Result<Vec<i64>> {
+        let mut res = VectorInt::new()?;
+        eids.with_raw(|es| unsafe {
+            check(igraph_count_multiple(self.as_ptr(), res.as_mut_ptr(), es))
+        })?;
+        Ok(res.to_vec())
+    }
+
+    /// Return, for each selected edge, whether an edge in the opposite
+    /// direction exists. With `loops`, self-loops count as mutual. All edges
+    /// of an undirected graph are mutual.
+    pub fn is_mutual(&self, eids: EdgeSelector<'_>, loops: bool) -> Result<Vec<bool>> {
+        let mut res = VectorBool::new()?;
+        eids.with_raw(|es| unsafe {
+            check(igraph_is_mutual(self.as_ptr(), res.as_mut_ptr(), es, loops))
+        })?;
+        Ok(res.to_vec())
+    }
+
+    /// Return the number of self-loops.
+    pub fn count_loops(&self) -> Result<i64> {
+        let mut res: i64 = 0;
+        unsafe {
+            check(igraph_count_loops(self.as_ptr(), &mut res))?;
+        }
+        Ok(res)
+    }
+
+    /// Return the ratio of the number (or total weight) of edges to the
+    /// number of possible edges. With `loops`, self-loops count as possible
+    /// edges.
+    pub fn density(&self, weights: Option<&[f64]>, loops: bool) -> Result<f64> {
+        let w = weights.map(Vector::from_slice).transpose()?;
+        let mut res: f64 = 0.0;
+        unsafe {
+            check(igraph_density(
+                self.as_ptr(),
+                w.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                &mut res,
+                loops,
+            ))?;
+        }
+        Ok(res)
+    }
+
+    /// Return the largest degree among the selected vertices.
+    pub fn maxdegree(
+        &self,
+        vids: VertexSelector<'_>,
+        mode: NeighborMode,
+        loops: Loops,
+    ) -> Result<i64> {
+        let mut res: i64 = 0;
+        vids.with_raw(|vs| unsafe {
+            check(igraph_maxdegree(
+                self.as_ptr(),
+                &mut res,
+                vs,
+                mode.to_raw(),
+                loops.to_raw(),
+            ))
+        })?;
+        Ok(res)
+    }
+
+    /// Return the mean vertex degree. Without `loops`, self-loops are not
+    /// counted.
+    pub fn mean_degree(&self, loops: bool) -> Result<f64> {
+        let mut res: f64 = 0.0;
+        unsafe {
+            check(igraph_mean_degree(self.as_ptr(), &mut res, loops))?;
+        }
+        Ok(res)
+    }
+
+    /// Return the reciprocity of a directed graph.
+    ///
+    /// `ReciprocityMode::Default` gives the fraction of edges that are
+    /// reciprocated; `ReciprocityMode::Ratio` the fraction of connected
+    /// vertex pairs that are connected both ways.
+    pub fn reciprocity(&self, ignore_loops: bool, mode: ReciprocityMode) -> Result<f64> {
+        let mut res: f64 = 0.0;
+        unsafe {
+            check(igraph_reciprocity(
+                self.as_ptr(),
+                &mut res,
+                ignore_loops,
+                mode.to_raw(),
+            ))?;
+        }
+        Ok(res)
+    }
 }
@@ -0,0 +1,104 @@
+use igraph_sys::*;
+
+use super::Graph;
+use crate::error::{Error, Result, check};
+use crate::rng::SeededRng;
+use crate::types::{MstAlgorithm, NeighborMode};
+use crate::vector::{Vector, VectorInt};
+
+impl Graph {
+    /// Compute a minimum spanning forest and return its edge ids.
+    ///
+    /// `MstAlgorithm::Prim` and `MstAlgorithm::Kruskal` require `weights`;
+    /// `MstAlgorithm::Automatic` picks a suitable method for the input.
+    pub fn minimum_spanning_tree(
+        &self,
+        weights: Option<&[f64]>,
+        algo: MstAlgorithm,
+    ) -> Result<Vec<i64>> {
+        if weights.is_none() && matches!(algo, MstAlgorithm::Prim | MstAlgorithm::Kruskal) {
+            return Err(Error::InvalidValue);
+        }
+        let w = weights.map(Vector::from_slice).transpose()?;
+        let mut res = VectorInt::new()?;
+        unsafe {
+            check(igraph_minimum_spanning_tree(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                w.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                algo.to_raw(),
+            ))?;
+        }
+        Ok(res.to_vec())
+    }
+
+    /// Compute a minimum spanning forest as a new graph.
+    ///
+    /// The result keeps all vertices of this graph and only the edges
+    /// returned by [`Graph::minimum_spanning_tree`].
+    pub fn minimum_spanning_tree_graph(
+        &self,
+        weights: Option<&[f64]>,
+        algo: MstAlgorithm,
+    ) -> Result<Graph> {
+        let eids = self.minimum_spanning_tree(weights, algo)?;
+        self.subgraph_from_edges(&eids, false)
+    }
+
+    /// Sample a spanning tree uniformly at random and return its edge ids.
+    ///
+    /// With `vid`, only the component containing that vertex is spanned;
+    /// otherwise a spanning forest of the whole graph is returned.
+    pub fn random_spanning_tree(&self, vid: Option<i64>, seed: Option<u64>) -> Result<Vec<i64>> {
+        let _rng = seed.map(SeededRng::new).transpose()?;
+        let mut res = VectorInt::new()?;
+        unsafe {
+            check(igraph_random_spanning_tree(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                vid.unwrap_or(-1),
+            ))?;
+        }
+        Ok(res.to_vec())
+    }
+
+    /// Compute a graph spanner and return its edge ids.
+    ///
+    /// Distances in the spanner are at most `stretch` times the distances
+    /// in the original graph. `stretch` must be at least 1.
+    pub fn spanner(&self, stretch: f64, weights: Option<&[f64]>) -> Result<Vec<i64>> {
+        if stretch.is_nan() || stretch < 1.0 {
+            return Err(Error::InvalidValue);
+        }
+        let w = weights.map(Vector::from_slice).transpose()?;
+        let mut res = VectorInt::new()?;
+        unsafe {
+            check(igraph_spanner(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                stretch,
+                w.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+            ))?;
+        }
+        Ok(res.to_vec())
+    }
+
+    /// Unfold the graph into a forest by breadth-first search from `roots`.
+    ///
+    /// Vertices reached along more than one edge are duplicated. Returns the
+    /// forest and, for each of its vertices, the id of the original vertex.
+    pub fn unfold_tree(&self, mode: NeighborMode, roots: &[i64]) -> Result<(Graph, Vec<i64>)> {
+        let r = VectorInt::from_slice(roots)?;
+        let mut index = VectorInt::new()?;
+        let tree = Graph::init_with(|res| unsafe {
+            igraph_unfold_tree(
+                self.as_ptr(),
+                res,
+                mode.to_raw(),
+                r.as_ptr(),
+                index.as_mut_ptr(),
+            )
+        })?;
+        Ok((tree, index.to_vec()))
+    }
+}
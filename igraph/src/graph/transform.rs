@@ -47,6 +47,30 @@ impl Graph {
         })
     }
 
+    /// Create the subgraph made of the given edges.
+    ///
+    /// With `delete_vertices`, vertices not incident to any of the edges are
+    /// dropped and the rest are renumbered in increasing id order.
+    pub(crate) fn subgraph_from_edges(&self, eids: &[i64], delete_vertices: bool) -> Result<Graph> {
+        let e = VectorInt::from_slice(eids)?;
+        Graph::init_with(|res| unsafe {
+            igraph_subgraph_from_edges(
+                self.as_ptr(),
+                res,
+                igraph_ess_vector(e.as_ptr()),
+                delete_vertices,
+            )
+        })
+    }
+
+    /// Create a copy of the graph with its vertices relabeled.
+    ///
+    /// Vertex `v` of this graph becomes vertex `permutation[v]` of the result.
+    pub fn permute_vertices(&self, permutation: &[i64]) -> Result<Graph> {
+        let p = VectorInt::from_slice(permutation)?;
+        Graph::init_with(|res| unsafe { igraph_permute_vertices(self.as_ptr(), res, p.as_ptr()) })
+    }
+
     /// Create the union of this graph and another graph.
     ///
     /// The result has max(n1, n2) vertices and the union of both edge sets.
@@ -0,0 +1,136 @@
+use igraph_sys::*;
+
+use super::Graph;
+use crate::error::{Result, check};
+use crate::selector::{EdgeSelector, VertexSelector};
+use crate::types::TransitivityMode;
+use crate::vector::Vector;
+
+impl Graph {
+    /// Compute the global transitivity (clustering coefficient), the ratio
+    /// of closed to connected triples.
+    ///
+    /// Edge directions are ignored. `mode` decides whether a graph without
+    /// connected triples yields zero or NaN.
+    pub fn transitivity(&self, mode: TransitivityMode) -> Result<f64> {
+        let mut res: f64 = 0.0;
+        unsafe {
+            check(igraph_transitivity_undirected(
+                self.as_ptr(),
+                &mut res,
+                mode.to_raw(),
+            ))?;
+        }
+        Ok(res)
+    }
+
+    /// Compute the local transitivity of the selected vertices.
+    ///
+    /// Edge directions are ignored. `mode` decides whether vertices with
+    /// fewer than two neighbors get zero or NaN.
+    pub fn transitivity_local(
+        &self,
+        vids: VertexSelector<'_>,
+        mode: TransitivityMode,
+    ) -> Result<Vec<f64>> {
+        let mut res = Vector::new()?;
+        vids.with_raw(|vs| unsafe {
+            check(igraph_transitivity_local_undirected(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                vs,
+                mode.to_raw(),
+            ))
+        })?;
+        Ok(res.to_vec())
+    }
+
+    /// Compute the average local transitivity over all vertices.
+    ///
+    /// With `TransitivityMode::Nan`, vertices with fewer than two neighbors
+    /// are left out of the average; with `TransitivityMode::Zero` they count
+    /// as zero.
+    pub fn transitivity_avglocal(&self, mode: TransitivityMode) -> Result<f64> {
+        let mut res: f64 = 0.0;
+        unsafe {
+            check(igraph_transitivity_avglocal_undirected(
+                self.as_ptr(),
+                &mut res,
+                mode.to_raw(),
+            ))?;
+        }
+        Ok(res)
+    }
+
+    /// Compute Barrat's weighted local transitivity of the selected vertices.
+    ///
+    /// Without `weights` this is the same as [`Graph::transitivity_local`].
+    pub fn transitivity_barrat(
+        &self,
+        vids: VertexSelector<'_>,
+        weights: Option<&[f64]>,
+        mode: TransitivityMode,
+    ) -> Result<Vec<f64>> {
+        let w = weights.map(Vector::from_slice).transpose()?;
+        let mut res = Vector::new()?;
+        vids.with_raw(|vs| unsafe {
+            check(igraph_transitivity_barrat(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                vs,
+                w.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                mode.to_raw(),
+            ))
+        })?;
+        Ok(res.to_vec())
+    }
+
+    /// Count the triangles in the graph, ignoring edge directions.
+    pub fn count_triangles(&self) -> Result<f64> {
+        let mut res: f64 = 0.0;
+        unsafe {
+            check(igraph_count_triangles(self.as_ptr(), &mut res))?;
+        }
+        Ok(res)
+    }
+
+    /// Count the triangles each selected vertex is part of, ignoring edge
+    /// directions.
+    pub fn count_adjacent_triangles(&self, vids: VertexSelector<'_>) -> Result<Vec<f64>> {
+        let mut res = Vector::new()?;
+        vids.with_raw(|vs| unsafe {
+            check(igraph_count_adjacent_triangles(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                vs,
+            ))
+        })?;
+        Ok(res.to_vec())
+    }
+
+    /// Compute the edge clustering coefficient of the selected edges.
+    ///
+    /// This counts the cycles of length `k` (3 or 4) through each edge. With
+    /// `offset`, one is added to the count; with `normalize`, it is divided
+    /// by the largest number of such cycles the endpoint degrees allow.
+    pub fn ecc(
+        &self,
+        eids: EdgeSelector<'_>,
+        k: i64,
+        offset: bool,
+        normalize: bool,
+    ) -> Result<Vec<f64>> {
+        let mut res = Vector::new()?;
+        eids.with_raw(|es| unsafe {
+            check(igraph_ecc(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                es,
+                k,
+                offset,
+                normalize,
+            ))
+        })?;
+        Ok(res.to_vec())
+    }
+}
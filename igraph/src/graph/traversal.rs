@@ -0,0 +1,385 @@
+use std::collections::VecDeque;
+use std::iter::FusedIterator;
+use std::ops::ControlFlow;
+use std::os::raw::c_void;
+
+use igraph_sys::*;
+
+use super::Graph;
+use crate::callback::PanicSlot;
+use crate::error::{Error, Result, check};
+use crate::types::{BfsResult, BfsVisit, DfsEvent, DfsResult, NeighborMode};
+use crate::vector::VectorInt;
+
+/// State shared with the BFS callback trampoline through igraph's `extra` pointer.
+struct BfsContext<'a> {
+    handler: &'a mut dyn FnMut(BfsVisit) -> ControlFlow<()>,
+    panic: PanicSlot,
+}
+
+unsafe extern "C" fn bfs_trampoline(
+    _graph: *const igraph_t,
+    vid: igraph_int_t,
+    pred: igraph_int_t,
+    succ: igraph_int_t,
+    rank: igraph_int_t,
+    dist: igraph_int_t,
+    extra: *mut c_void,
+) -> igraph_error_t {
+    let ctx = unsafe { &mut *extra.cast::<BfsContext>() };
+    let visit = BfsVisit {
+        vid,
+        pred,
+        succ,
+        rank,
+        dist,
+    };
+    let handler = &mut ctx.handler;
+    ctx.panic.call_handler(|| handler(visit))
+}
+
+/// State shared with the DFS callback trampolines through igraph's `extra` pointer.
+struct DfsContext<'a> {
+    handler: &'a mut dyn FnMut(DfsEvent) -> ControlFlow<()>,
+    panic: PanicSlot,
+}
+
+impl DfsContext<'_> {
+    fn dispatch(&mut self, event: DfsEvent) -> igraph_error_t {
+        let handler = &mut self.handler;
+        self.panic.call_handler(|| handler(event))
+    }
+}
+
+unsafe extern "C" fn dfs_in_trampoline(
+    _graph: *const igraph_t,
+    vid: igraph_int_t,
+    dist: igraph_int_t,
+    extra: *mut c_void,
+) -> igraph_error_t {
+    let ctx = unsafe { &mut *extra.cast::<DfsContext>() };
+    ctx.dispatch(DfsEvent::Discover { vid, dist })
+}
+
+unsafe extern "C" fn dfs_out_trampoline(
+    _graph: *const igraph_t,
+    vid: igraph_int_t,
+    dist: igraph_int_t,
+    extra: *mut c_void,
+) -> igraph_error_t {
+    let ctx = unsafe { &mut *extra.cast::<DfsContext>() };
+    ctx.dispatch(DfsEvent::Finish { vid, dist })
+}
+
+/// Drop the -1 padding igraph leaves for vertices that were never visited.
+fn visited(order: &VectorInt) -> Vec<i64> {
+    order.to_vec().into_iter().filter(|&v| v >= 0).collect()
+}
+
+impl Graph {
+    /// Run a breadth-first search from each of `roots` in turn.
+    ///
+    /// If `unreachable` is set, the search continues from the remaining
+    /// vertices once the roots are exhausted. With `restricted`, only those
+    /// vertices are visited.
+    pub fn bfs(
+        &self,
+        roots: &[i64],
+        mode: NeighborMode,
+        unreachable: bool,
+        restricted: Option<&[i64]>,
+    ) -> Result<BfsResult> {
+        let r = VectorInt::from_slice(roots)?;
+        let restricted = restricted.map(VectorInt::from_slice).transpose()?;
+        let mut order = VectorInt::new()?;
+        let mut rank = VectorInt::new()?;
+        let mut parents = VectorInt::new()?;
+        let mut pred = VectorInt::new()?;
+        let mut succ = VectorInt::new()?;
+        let mut dist = VectorInt::new()?;
+        unsafe {
+            check(igraph_bfs(
+                self.as_ptr(),
+                0, // root, ignored when roots is given
+                r.as_ptr(),
+                mode.to_raw(),
+                unreachable,
+                restricted.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                order.as_mut_ptr(),
+                rank.as_mut_ptr(),
+                parents.as_mut_ptr(),
+                pred.as_mut_ptr(),
+                succ.as_mut_ptr(),
+                dist.as_mut_ptr(),
+                None,                 // callback
+                std::ptr::null_mut(), // extra
+            ))?;
+        }
+        Ok(BfsResult {
+            order: visited(&order),
+            rank: rank.to_vec(),
+            parents: parents.to_vec(),
+            pred: pred.to_vec(),
+            succ: succ.to_vec(),
+            dist: dist.to_vec(),
+        })
+    }
+
+    /// Run a breadth-first search, calling `visitor` as each vertex is reached.
+    ///
+    /// Return [`ControlFlow::Break`] from `visitor` to stop the search.
+    /// `unreachable` and `restricted` behave as in [`Graph::bfs`].
+    pub fn bfs_callback(
+        &self,
+        roots: &[i64],
+        mode: NeighborMode,
+        unreachable: bool,
+        restricted: Option<&[i64]>,
+        mut visitor: impl FnMut(BfsVisit) -> ControlFlow<()>,
+    ) -> Result<()> {
+        let r = VectorInt::from_slice(roots)?;
+        let restricted = restricted.map(VectorInt::from_slice).transpose()?;
+        let mut ctx = BfsContext {
+            handler: &mut visitor,
+            panic: PanicSlot::default(),
+        };
+        let code = unsafe {
+            igraph_bfs(
+                self.as_ptr(),
+                0, // root, ignored when roots is given
+                r.as_ptr(),
+                mode.to_raw(),
+                unreachable,
+                restricted.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                std::ptr::null_mut(), // order
+                std::ptr::null_mut(), // rank
+                std::ptr::null_mut(), // parents
+                std::ptr::null_mut(), // pred
+                std::ptr::null_mut(), // succ
+                std::ptr::null_mut(), // dist
+                Some(bfs_trampoline),
+                (&mut ctx as *mut BfsContext).cast(),
+            )
+        };
+        ctx.panic.resume();
+        check(code)
+    }
+
+    /// Run a breadth-first search from a single root, visiting only the
+    /// vertices reachable from it.
+    ///
+    /// Returns `(order, layers, parents)`: layer `i` holds the vertices
+    /// `order[layers[i]..layers[i + 1]]`, and `parents` is indexed by vertex
+    /// id (negative for the root and unreached vertices).
+    pub fn bfs_simple(
+        &self,
+        root: i64,
+        mode: NeighborMode,
+    ) -> Result<(Vec<i64>, Vec<i64>, Vec<i64>)> {
+        let mut order = VectorInt::new()?;
+        let mut layers = VectorInt::new()?;
+        let mut parents = VectorInt::new()?;
+        unsafe {
+            check(igraph_bfs_simple(
+                self.as_ptr(),
+                root,
+                mode.to_raw(),
+                order.as_mut_ptr(),
+                layers.as_mut_ptr(),
+                parents.as_mut_ptr(),
+            ))?;
+        }
+        Ok((order.to_vec(), layers.to_vec(), parents.to_vec()))
+    }
+
+    /// Run a depth-first search from `root`.
+    ///
+    /// If `unreachable` is set, the search continues from the remaining
+    /// vertices once everything reachable from `root` has been visited.
+    pub fn dfs(&self, root: i64, mode: NeighborMode, unreachable: bool) -> Result<DfsResult> {
+        let mut order = VectorInt::new()?;
+        let mut order_out = VectorInt::new()?;
+        let mut parents = VectorInt::new()?;
+        let mut dist = VectorInt::new()?;
+        unsafe {
+            check(igraph_dfs(
+                self.as_ptr(),
+                root,
+                mode.to_raw(),
+                unreachable,
+                order.as_mut_ptr(),
+                order_out.as_mut_ptr(),
+                parents.as_mut_ptr(),
+                dist.as_mut_ptr(),
+                None,                 // in_callback
+                None,                 // out_callback
+                std::ptr::null_mut(), // extra
+            ))?;
+        }
+        Ok(DfsResult {
+            order: visited(&order),
+            order_out: visited(&order_out),
+            parents: parents.to_vec(),
+            dist: dist.to_vec(),
+        })
+    }
+
+    /// Run a depth-first search, calling `visitor` when each vertex is
+    /// discovered and again when its subtree is finished.
+    ///
+    /// Return [`ControlFlow::Break`] from `visitor` to stop the search.
+    pub fn dfs_callback(
+        &self,
+        root: i64,
+        mode: NeighborMode,
+        unreachable: bool,
+        mut visitor: impl FnMut(DfsEvent) -> ControlFlow<()>,
+    ) -> Result<()> {
+        let mut ctx = DfsContext {
+            handler: &mut visitor,
+            panic: PanicSlot::default(),
+        };
+        let code = unsafe {
+            igraph_dfs(
+                self.as_ptr(),
+                root,
+                mode.to_raw(),
+                unreachable,
+                std::ptr::null_mut(), // order
+                std::ptr::null_mut(), // order_out
+                std::ptr::null_mut(), // parents
+                std::ptr::null_mut(), // dist
+                Some(dfs_in_trampoline),
+                Some(dfs_out_trampoline),
+                (&mut ctx as *mut DfsContext).cast(),
+            )
+        };
+        ctx.panic.resume();
+        check(code)
+    }
+}
+
+/// Lazy breadth-first iterator created by [`Graph::bfs_iter`].
+///
+/// Yields `(vertex, depth, parent)` for each vertex reachable from the root,
+/// looking up neighbors only as vertices are reached.
+pub struct BfsIter<'a> {
+    graph: &'a Graph,
+    mode: NeighborMode,
+    seen: Vec<bool>,
+    queue: VecDeque<(i64, i64, Option<i64>)>,
+}
+
+impl Iterator for BfsIter<'_> {
+    type Item = (i64, i64, Option<i64>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (vid, depth, parent) = self.queue.pop_front()?;
+        let neis = self
+            .graph
+            .neighbors(vid, self.mode)
+            .expect("neighbors of a valid vertex");
+        for n in neis {
+            if !std::mem::replace(&mut self.seen[n as usize], true) {
+                self.queue.push_back((n, depth + 1, Some(vid)));
+            }
+        }
+        Some((vid, depth, parent))
+    }
+}
+
+impl FusedIterator for BfsIter<'_> {}
+
+/// Lazy depth-first iterator created by [`Graph::dfs_iter`].
+///
+/// Yields `(vertex, depth, parent)` in discovery order for each vertex
+/// reachable from the root, looking up neighbors only as vertices are reached.
+pub struct DfsIter<'a> {
+    graph: &'a Graph,
+    mode: NeighborMode,
+    seen: Vec<bool>,
+    root: Option<i64>,
+    /// Vertices on the current path, with their neighbors and how many of
+    /// those have been examined.
+    stack: Vec<(i64, Vec<i64>, usize)>,
+}
+
+impl DfsIter<'_> {
+    fn enter(&mut self, vid: i64) {
+        self.seen[vid as usize] = true;
+        let neis = self
+            .graph
+            .neighbors(vid, self.mode)
+            .expect("neighbors of a valid vertex");
+        self.stack.push((vid, neis, 0));
+    }
+}
+
+impl Iterator for DfsIter<'_> {
+    type Item = (i64, i64, Option<i64>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(root) = self.root.take() {
+            self.enter(root);
+            return Some((root, 0, None));
+        }
+        while let Some((vid, neis, pos)) = self.stack.last_mut() {
+            let parent = *vid;
+            match neis[*pos..].iter().position(|&n| !self.seen[n as usize]) {
+                Some(offset) => {
+                    let n = neis[*pos + offset];
+                    *pos += offset + 1;
+                    let depth = self.stack.len() as i64;
+                    self.enter(n);
+                    return Some((n, depth, Some(parent)));
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+        None
+    }
+}
+
+impl FusedIterator for DfsIter<'_> {}
+
+impl Graph {
+    /// Iterate lazily over the vertices reachable from `root` in
+    /// breadth-first order.
+    ///
+    /// Each item is `(vertex, depth, parent)`, with no parent for the root.
+    pub fn bfs_iter(&self, root: i64, mode: NeighborMode) -> Result<BfsIter<'_>> {
+        let mut seen = self.traversal_seen(root)?;
+        seen[root as usize] = true;
+        Ok(BfsIter {
+            graph: self,
+            mode,
+            seen,
+            queue: VecDeque::from([(root, 0, None)]),
+        })
+    }
+
+    /// Iterate lazily over the vertices reachable from `root` in
+    /// depth-first order.
+    ///
+    /// Each item is `(vertex, depth, parent)`, with no parent for the root.
+    pub fn dfs_iter(&self, root: i64, mode: NeighborMode) -> Result<DfsIter<'_>> {
+        let seen = self.traversal_seen(root)?;
+        Ok(DfsIter {
+            graph: self,
+            mode,
+            seen,
+            root: Some(root),
+            stack: Vec::new(),
+        })
+    }
+
+    fn traversal_seen(&self, root: i64) -> Result<Vec<bool>> {
+        let n = self.vcount();
+        if root < 0 || root >= n {
+            return Err(Error::InvalidVertexId);
+        }
+        Ok(vec![false; n as usize])
+    }
+}
@@ -0,0 +1,7 @@
+//! Conversions to and from external linear algebra crates, enabled by the
+//! `ndarray` and `nalgebra` cargo features.
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "ndarray")]
+mod ndarray;
@@ -0,0 +1,117 @@
+use ::nalgebra::{DMatrix, DMatrixView, DMatrixViewMut, DVector, DVectorView, DVectorViewMut};
+
+use crate::error::Result;
+use crate::matrix::Matrix;
+use crate::vector::{Vector, VectorInt};
+
+// `Matrix` and `DMatrix` are both column-major, so every conversion here is
+// a straight copy of the storage, and views need no copy at all.
+
+impl Matrix {
+    /// Borrow the matrix as a `nalgebra` view without copying.
+    pub fn dmatrix_view(&self) -> DMatrixView<'_, f64> {
+        DMatrixView::from_slice(self.as_slice(), self.nrow() as usize, self.ncol() as usize)
+    }
+
+    /// Mutably borrow the matrix as a `nalgebra` view without copying.
+    pub fn dmatrix_view_mut(&mut self) -> DMatrixViewMut<'_, f64> {
+        let (nrow, ncol) = (self.nrow() as usize, self.ncol() as usize);
+        DMatrixViewMut::from_slice(self.as_mut_slice(), nrow, ncol)
+    }
+
+    /// Copy the matrix into an owned `DMatrix`.
+    pub fn to_dmatrix(&self) -> DMatrix<f64> {
+        DMatrix::from_column_slice(self.nrow() as usize, self.ncol() as usize, self.as_slice())
+    }
+
+    /// Copy a `DMatrix` into a new matrix.
+    pub fn from_dmatrix(matrix: &DMatrix<f64>) -> Result<Self> {
+        Self::from_column_major(
+            matrix.nrows() as i64,
+            matrix.ncols() as i64,
+            matrix.as_slice(),
+        )
+    }
+}
+
+impl Vector {
+    /// Borrow the vector as a `nalgebra` view without copying.
+    pub fn dvector_view(&self) -> DVectorView<'_, f64> {
+        DVectorView::from_slice(self.as_slice(), self.len() as usize)
+    }
+
+    /// Mutably borrow the vector as a `nalgebra` view without copying.
+    pub fn dvector_view_mut(&mut self) -> DVectorViewMut<'_, f64> {
+        let len = self.len() as usize;
+        DVectorViewMut::from_slice(self.as_mut_slice(), len)
+    }
+
+    /// Copy the vector into an owned `DVector`.
+    pub fn to_dvector(&self) -> DVector<f64> {
+        DVector::from_column_slice(self.as_slice())
+    }
+
+    /// Copy a `DVector` into a new vector.
+    pub fn from_dvector(vector: &DVector<f64>) -> Result<Self> {
+        Self::from_slice(vector.as_slice())
+    }
+}
+
+impl VectorInt {
+    /// Borrow the vector as a `nalgebra` view without copying.
+    pub fn dvector_view(&self) -> DVectorView<'_, i64> {
+        DVectorView::from_slice(self.as_slice(), self.len() as usize)
+    }
+
+    /// Mutably borrow the vector as a `nalgebra` view without copying.
+    pub fn dvector_view_mut(&mut self) -> DVectorViewMut<'_, i64> {
+        let len = self.len() as usize;
+        DVectorViewMut::from_slice(self.as_mut_slice(), len)
+    }
+
+    /// Copy the vector into an owned `DVector`.
+    pub fn to_dvector(&self) -> DVector<i64> {
+        DVector::from_column_slice(self.as_slice())
+    }
+
+    /// Copy a `DVector` into a new vector.
+    pub fn from_dvector(vector: &DVector<i64>) -> Result<Self> {
+        Self::from_slice(vector.as_slice())
+    }
+}
+
+impl From<&Matrix> for DMatrix<f64> {
+    fn from(value: &Matrix) -> Self {
+        value.to_dmatrix()
+    }
+}
+
+impl From<Matrix> for DMatrix<f64> {
+    fn from(value: Matrix) -> Self {
+        value.to_dmatrix()
+    }
+}
+
+impl From<&Vector> for DVector<f64> {
+    fn from(value: &Vector) -> Self {
+        value.to_dvector()
+    }
+}
+
+impl From<Vector> for DVector<f64> {
+    fn from(value: Vector) -> Self {
+        value.to_dvector()
+    }
+}
+
+impl From<&VectorInt> for DVector<i64> {
+    fn from(value: &VectorInt) -> Self {
+        value.to_dvector()
+    }
+}
+
+impl From<VectorInt> for DVector<i64> {
+    fn from(value: VectorInt) -> Self {
+        value.to_dvector()
+    }
+}
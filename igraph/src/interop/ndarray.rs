@@ -0,0 +1,129 @@
+use ::ndarray::{
+    Array1, Array2, ArrayBase, ArrayView1, ArrayView2, ArrayViewMut1, ArrayViewMut2, Data, Ix1,
+    Ix2, ShapeBuilder,
+};
+
+use crate::error::Result;
+use crate::matrix::Matrix;
+use crate::vector::{Vector, VectorInt};
+
+impl Matrix {
+    /// Borrow the matrix as an `ndarray` view without copying.
+    pub fn ndarray_view(&self) -> ArrayView2<'_, f64> {
+        let shape = (self.nrow() as usize, self.ncol() as usize).f();
+        ArrayView2::from_shape(shape, self.as_slice()).expect("matrix storage matches its shape")
+    }
+
+    /// Mutably borrow the matrix as an `ndarray` view without copying.
+    pub fn ndarray_view_mut(&mut self) -> ArrayViewMut2<'_, f64> {
+        let shape = (self.nrow() as usize, self.ncol() as usize).f();
+        ArrayViewMut2::from_shape(shape, self.as_mut_slice())
+            .expect("matrix storage matches its shape")
+    }
+
+    /// Copy the matrix into an owned `Array2`.
+    pub fn to_ndarray(&self) -> Array2<f64> {
+        self.ndarray_view().to_owned()
+    }
+
+    /// Copy any two-dimensional `ndarray` into a new matrix. Contiguous
+    /// arrays in either memory order are copied in bulk.
+    pub fn from_ndarray<S: Data<Elem = f64>>(array: &ArrayBase<S, Ix2>) -> Result<Self> {
+        let (nrow, ncol) = array.dim();
+        let (nrow, ncol) = (nrow as i64, ncol as i64);
+        if let Some(data) = array.as_slice() {
+            Self::from_row_major(nrow, ncol, data)
+        } else if let Some(data) = array.t().as_slice() {
+            Self::from_column_major(nrow, ncol, data)
+        } else {
+            let data: Vec<f64> = array.t().iter().copied().collect();
+            Self::from_column_major(nrow, ncol, &data)
+        }
+    }
+}
+
+impl Vector {
+    /// Borrow the vector as an `ndarray` view without copying.
+    pub fn ndarray_view(&self) -> ArrayView1<'_, f64> {
+        ArrayView1::from(self.as_slice())
+    }
+
+    /// Mutably borrow the vector as an `ndarray` view without copying.
+    pub fn ndarray_view_mut(&mut self) -> ArrayViewMut1<'_, f64> {
+        ArrayViewMut1::from(self.as_mut_slice())
+    }
+
+    /// Copy the vector into an owned `Array1`.
+    pub fn to_ndarray(&self) -> Array1<f64> {
+        Array1::from(self.to_vec())
+    }
+
+    /// Copy a one-dimensional `ndarray` into a new vector.
+    pub fn from_ndarray<S: Data<Elem = f64>>(array: &ArrayBase<S, Ix1>) -> Result<Self> {
+        match array.as_slice() {
+            Some(data) => Self::from_slice(data),
+            None => Self::from_slice(&array.to_vec()),
+        }
+    }
+}
+
+impl VectorInt {
+    /// Borrow the vector as an `ndarray` view without copying.
+    pub fn ndarray_view(&self) -> ArrayView1<'_, i64> {
+        ArrayView1::from(self.as_slice())
+    }
+
+    /// Mutably borrow the vector as an `ndarray` view without copying.
+    pub fn ndarray_view_mut(&mut self) -> ArrayViewMut1<'_, i64> {
+        ArrayViewMut1::from(self.as_mut_slice())
+    }
+
+    /// Copy the vector into an owned `Array1`.
+    pub fn to_ndarray(&self) -> Array1<i64> {
+        Array1::from(self.to_vec())
+    }
+
+    /// Copy a one-dimensional `ndarray` into a new vector.
+    pub fn from_ndarray<S: Data<Elem = i64>>(array: &ArrayBase<S, Ix1>) -> Result<Self> {
+        match array.as_slice() {
+            Some(data) => Self::from_slice(data),
+            None => Self::from_slice(&array.to_vec()),
+        }
+    }
+}
+
+impl From<&Matrix> for Array2<f64> {
+    fn from(value: &Matrix) -> Self {
+        value.to_ndarray()
+    }
+}
+
+impl From<Matrix> for Array2<f64> {
+    fn from(value: Matrix) -> Self {
+        value.to_ndarray()
+    }
+}
+
+impl From<&Vector> for Array1<f64> {
+    fn from(value: &Vector) -> Self {
+        value.to_ndarray()
+    }
+}
+
+impl From<Vector> for Array1<f64> {
+    fn from(value: Vector) -> Self {
+        value.to_ndarray()
+    }
+}
+
+impl From<&VectorInt> for Array1<i64> {
+    fn from(value: &VectorInt) -> Self {
+        value.to_ndarray()
+    }
+}
+
+impl From<VectorInt> for Array1<i64> {
+    fn from(value: VectorInt) -> Self {
+        value.to_ndarray()
+    }
+}
@@ -1,11 +1,18 @@
+pub mod adjlist;
+mod callback;
 pub mod error;
 pub mod graph;
+mod interop;
 pub mod matrix;
+mod rng;
+pub mod selector;
 pub mod types;
 pub mod vector;
 
+pub use adjlist::{AdjList, IncList, LazyAdjList, LazyIncList};
 pub use error::{Error, Result};
-pub use graph::Graph;
+pub use graph::{BfsIter, DfsIter, EdgeIter, Graph, VertexIter};
 pub use matrix::Matrix;
+pub use selector::{EdgeSelector, VertexSelector};
 pub use types::*;
-pub use vector::{Vector, VectorInt, VectorIntList};
+pub use vector::{Vector, VectorBool, VectorInt, VectorIntList};
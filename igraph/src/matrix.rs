@@ -1,10 +1,15 @@
+use std::fmt;
 use std::mem::MaybeUninit;
+use std::ops::{Index, IndexMut};
 
 use igraph_sys::*;
 
-use crate::error::{Result, check};
+use crate::error::{Error, Result, check};
+use crate::vector::{raw_slice, raw_slice_mut};
 
 /// Safe wrapper around `igraph_matrix_t` (matrix of `f64`).
+///
+/// Elements are stored in column-major order, as in igraph.
 pub struct Matrix {
     pub(crate) inner: igraph_matrix_t,
 }
@@ -20,6 +25,59 @@ impl Matrix {
         }
     }
 
+    /// Build an `nrow` x `ncol` matrix from column-major data in a single
+    /// bulk copy. Returns `Error::InvalidValue` if the length of `data` does
+    /// not match.
+    pub fn from_column_major(nrow: i64, ncol: i64, data: &[f64]) -> Result<Self> {
+        Self::from_array(
+            nrow,
+            ncol,
+            data,
+            igraph_matrix_storage_t_IGRAPH_COLUMN_MAJOR,
+        )
+    }
+
+    /// Build an `nrow` x `ncol` matrix from row-major data. Returns
+    /// `Error::InvalidValue` if the length of `data` does not match.
+    pub fn from_row_major(nrow: i64, ncol: i64, data: &[f64]) -> Result<Self> {
+        Self::from_array(nrow, ncol, data, igraph_matrix_storage_t_IGRAPH_ROW_MAJOR)
+    }
+
+    fn from_array(
+        nrow: i64,
+        ncol: i64,
+        data: &[f64],
+        storage: igraph_matrix_storage_t,
+    ) -> Result<Self> {
+        if nrow < 0 || ncol < 0 || nrow.checked_mul(ncol) != Some(data.len() as i64) {
+            return Err(Error::InvalidValue);
+        }
+        let mut m = MaybeUninit::uninit();
+        unsafe {
+            check(igraph_matrix_init_array(
+                m.as_mut_ptr(),
+                data.as_ptr(),
+                nrow,
+                ncol,
+                storage,
+            ))?;
+            Ok(Self {
+                inner: m.assume_init(),
+            })
+        }
+    }
+
+    /// Build a matrix from a slice of rows. Returns `Error::InvalidValue` if
+    /// the rows differ in length.
+    pub fn from_rows<T: AsRef<[f64]>>(rows: &[T]) -> Result<Self> {
+        let ncol = rows.first().map_or(0, |r| r.as_ref().len());
+        if rows.iter().any(|r| r.as_ref().len() != ncol) {
+            return Err(Error::InvalidValue);
+        }
+        let data: Vec<f64> = rows.iter().flat_map(|r| r.as_ref()).copied().collect();
+        Self::from_row_major(rows.len() as i64, ncol as i64, &data)
+    }
+
     pub fn nrow(&self) -> i64 {
         unsafe { igraph_matrix_nrow(&self.inner) }
     }
@@ -28,16 +86,82 @@ impl Matrix {
         unsafe { igraph_matrix_ncol(&self.inner) }
     }
 
+    /// Return the element at (`row`, `col`), panicking if it is out of range.
     pub fn get(&self, row: i64, col: i64) -> f64 {
-        unsafe { igraph_matrix_get(&self.inner, row, col) }
+        self[(row as usize, col as usize)]
+    }
+
+    /// Borrow all elements in place, in column-major order.
+    pub fn as_slice(&self) -> &[f64] {
+        unsafe { raw_slice(self.inner.data.stor_begin, self.inner.data.end) }
+    }
+
+    /// Borrow all elements in place for modification, in column-major order.
+    pub fn as_mut_slice(&mut self) -> &mut [f64] {
+        unsafe { raw_slice_mut(self.inner.data.stor_begin, self.inner.data.end) }
+    }
+
+    /// Borrow column `col`, which is contiguous in storage.
+    pub fn column(&self, col: i64) -> &[f64] {
+        assert!(
+            (0..self.ncol()).contains(&col),
+            "matrix column out of range"
+        );
+        let nrow = self.nrow() as usize;
+        let start = col as usize * nrow;
+        &self.as_slice()[start..start + nrow]
+    }
+
+    /// Mutably borrow column `col`.
+    pub fn column_mut(&mut self, col: i64) -> &mut [f64] {
+        assert!(
+            (0..self.ncol()).contains(&col),
+            "matrix column out of range"
+        );
+        let nrow = self.nrow() as usize;
+        let start = col as usize * nrow;
+        &mut self.as_mut_slice()[start..start + nrow]
+    }
+
+    /// Iterate over the elements of row `row`. Rows are strided in storage,
+    /// so this is a view rather than a slice.
+    pub fn row(&self, row: i64) -> impl ExactSizeIterator<Item = f64> + '_ {
+        assert!((0..self.nrow()).contains(&row), "matrix row out of range");
+        let nrow = self.nrow() as usize;
+        self.as_slice()
+            .iter()
+            .skip(row as usize)
+            .step_by(nrow)
+            .copied()
+    }
+
+    /// Iterate over the columns as slices.
+    pub fn columns(&self) -> impl ExactSizeIterator<Item = &[f64]> + '_ {
+        (0..self.ncol() as usize).map(|c| self.column(c as i64))
+    }
+
+    /// Iterate over the rows; see [`Matrix::row`].
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = impl ExactSizeIterator<Item = f64> + '_> {
+        (0..self.nrow() as usize).map(|r| self.row(r as i64))
+    }
+
+    /// Iterate over all elements in column-major order.
+    pub fn iter(&self) -> std::slice::Iter<'_, f64> {
+        self.as_slice().iter()
+    }
+
+    /// Transpose the matrix in place.
+    pub fn transpose(&mut self) -> Result<()> {
+        unsafe { check(igraph_matrix_transpose(&mut self.inner)) }
+    }
+
+    /// Copy the elements out in row-major order.
+    pub fn to_row_major(&self) -> Vec<f64> {
+        self.rows().flatten().collect()
     }
 
     pub fn to_vec_of_vecs(&self) -> Vec<Vec<f64>> {
-        let nrow = self.nrow();
-        let ncol = self.ncol();
-        (0..nrow)
-            .map(|r| (0..ncol).map(|c| self.get(r, c)).collect())
-            .collect()
+        self.rows().map(Iterator::collect).collect()
     }
 
     pub(crate) fn as_mut_ptr(&mut self) -> *mut igraph_matrix_t {
@@ -45,6 +169,68 @@ impl Matrix {
     }
 }
 
+impl Index<(usize, usize)> for Matrix {
+    type Output = f64;
+
+    fn index(&self, (row, col): (usize, usize)) -> &f64 {
+        let nrow = self.nrow() as usize;
+        assert!(
+            row < nrow && col < self.ncol() as usize,
+            "matrix index out of range"
+        );
+        &self.as_slice()[col * nrow + row]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut f64 {
+        let nrow = self.nrow() as usize;
+        assert!(
+            row < nrow && col < self.ncol() as usize,
+            "matrix index out of range"
+        );
+        &mut self.as_mut_slice()[col * nrow + row]
+    }
+}
+
+impl<'a> IntoIterator for &'a Matrix {
+    type Item = &'a f64;
+    type IntoIter = std::slice::Iter<'a, f64>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Clone for Matrix {
+    fn clone(&self) -> Self {
+        let mut m = MaybeUninit::uninit();
+        unsafe {
+            check(igraph_matrix_init_copy(m.as_mut_ptr(), &self.inner))
+                .expect("failed to allocate igraph matrix");
+            Self {
+                inner: m.assume_init(),
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.rows().map(Iterator::collect::<Vec<_>>))
+            .finish()
+    }
+}
+
+impl PartialEq for Matrix {
+    fn eq(&self, other: &Self) -> bool {
+        self.nrow() == other.nrow()
+            && self.ncol() == other.ncol()
+            && self.as_slice() == other.as_slice()
+    }
+}
+
 impl Drop for Matrix {
     fn drop(&mut self) {
         unsafe { igraph_matrix_destroy(&mut self.inner) }
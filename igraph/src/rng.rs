@@ -0,0 +1,42 @@
+use std::mem::MaybeUninit;
+
+use igraph_sys::*;
+
+use crate::error::{Result, check};
+
+/// Temporarily replaces the thread's default igraph RNG with a freshly
+/// seeded one, so a single call is reproducible without disturbing the RNG
+/// state seen by other calls.
+///
+/// The default RNG is thread-local (we build with `IGRAPH_ENABLE_TLS=ON`);
+/// the previous one is restored when the guard is dropped.
+pub(crate) struct SeededRng {
+    // Boxed so the address handed to igraph stays stable.
+    rng: Box<igraph_rng_t>,
+    prev: *mut igraph_rng_t,
+}
+
+impl SeededRng {
+    pub(crate) fn new(seed: u64) -> Result<Self> {
+        let mut rng = Box::new(MaybeUninit::<igraph_rng_t>::uninit());
+        unsafe {
+            check(igraph_rng_init(rng.as_mut_ptr(), &igraph_rngtype_pcg32))?;
+            let mut rng = rng.assume_init();
+            if let Err(e) = check(igraph_rng_seed(&mut *rng, seed)) {
+                igraph_rng_destroy(&mut *rng);
+                return Err(e);
+            }
+            let prev = igraph_rng_set_default(&mut *rng);
+            Ok(Self { rng, prev })
+        }
+    }
+}
+
+impl Drop for SeededRng {
+    fn drop(&mut self) {
+        unsafe {
+            igraph_rng_set_default(self.prev);
+            igraph_rng_destroy(&mut *self.rng);
+        }
+    }
+}
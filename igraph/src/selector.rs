@@ -0,0 +1,155 @@
+use std::mem::MaybeUninit;
+
+use igraph_sys::*;
+
+use crate::error::{Result, check};
+use crate::types::NeighborMode;
+use crate::vector::VectorInt;
+
+/// Selects the vertices an operation applies to.
+///
+/// Borrowed id lists are passed to igraph without copying and only need to
+/// outlive the call they are used in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VertexSelector<'a> {
+    /// Every vertex, in increasing id order.
+    All,
+    /// A single vertex.
+    Single(i64),
+    /// The listed vertices, in the given order.
+    List(&'a [i64]),
+    /// The vertices with ids in `start..end`.
+    Range(i64, i64),
+    /// Each vertex adjacent to the given one, once.
+    Adjacent(i64, NeighborMode),
+    /// The vertices not adjacent to the given one.
+    NonAdjacent(i64, NeighborMode),
+}
+
+/// Selects the edges an operation applies to.
+///
+/// Borrowed id lists are only needed for the duration of the call they are
+/// used in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeSelector<'a> {
+    /// Every edge, in increasing id order.
+    All,
+    /// A single edge.
+    Single(i64),
+    /// The listed edges, in the given order.
+    List(&'a [i64]),
+    /// The edges with ids in `start..end`.
+    Range(i64, i64),
+    /// The edges incident to the given vertex; self-loops are listed once.
+    Incident(i64, NeighborMode),
+    /// One edge between each `(from, to)` pair. The flag selects whether
+    /// edge directions must match in directed graphs.
+    Pairs(&'a [(i64, i64)], bool),
+    /// The edges along a path through the listed vertices. The flag selects
+    /// whether edge directions must match in directed graphs.
+    Path(&'a [i64], bool),
+    /// All edges between two vertices, including multi-edges. The flag
+    /// selects whether edge directions must match in directed graphs.
+    AllBetween(i64, i64, bool),
+}
+
+/// Owned `igraph_vs_t`, destroyed when dropped.
+struct RawVs(igraph_vs_t);
+
+impl Drop for RawVs {
+    fn drop(&mut self) {
+        unsafe { igraph_vs_destroy(&mut self.0) }
+    }
+}
+
+/// Owned `igraph_es_t`, destroyed when dropped.
+struct RawEs(igraph_es_t);
+
+impl Drop for RawEs {
+    fn drop(&mut self) {
+        unsafe { igraph_es_destroy(&mut self.0) }
+    }
+}
+
+/// Build a selector with one of igraph's fallible `igraph_*s_*` constructors.
+fn init_raw<T>(f: impl FnOnce(*mut T) -> igraph_error_t) -> Result<T> {
+    let mut raw = MaybeUninit::uninit();
+    check(f(raw.as_mut_ptr()))?;
+    Ok(unsafe { raw.assume_init() })
+}
+
+/// View a slice as an igraph vector without copying.
+///
+/// The view must not be destroyed or outlive `data`.
+fn view(data: &[i64]) -> igraph_vector_int_t {
+    unsafe { igraph_vector_int_view(data.as_ptr(), data.len() as i64) }
+}
+
+impl VertexSelector<'_> {
+    /// Pass the raw selector to `f`, keeping any storage it refers to alive
+    /// for the duration of the call.
+    pub(crate) fn with_raw<R>(self, f: impl FnOnce(igraph_vs_t) -> Result<R>) -> Result<R> {
+        // Declared before `raw` so the view outlives the selector pointing to it.
+        let list;
+        let raw = RawVs(match self {
+            VertexSelector::All => unsafe { igraph_vss_all() },
+            VertexSelector::Single(vid) => unsafe { igraph_vss_1(vid) },
+            VertexSelector::List(ids) => {
+                list = view(ids);
+                unsafe { igraph_vss_vector(&list) }
+            }
+            VertexSelector::Range(start, end) => unsafe { igraph_vss_range(start, end) },
+            VertexSelector::Adjacent(vid, mode) => init_raw(|vs| unsafe {
+                igraph_vs_adj(
+                    vs,
+                    vid,
+                    mode.to_raw(),
+                    igraph_loops_t_IGRAPH_LOOPS_ONCE,
+                    false, // multiple
+                )
+            })?,
+            VertexSelector::NonAdjacent(vid, mode) => {
+                init_raw(|vs| unsafe { igraph_vs_nonadj(vs, vid, mode.to_raw()) })?
+            }
+        });
+        f(raw.0)
+    }
+}
+
+impl EdgeSelector<'_> {
+    /// Pass the raw selector to `f`, keeping any storage it refers to alive
+    /// for the duration of the call.
+    pub(crate) fn with_raw<R>(self, f: impl FnOnce(igraph_es_t) -> Result<R>) -> Result<R> {
+        // Declared before `raw` so the view outlives the selector pointing to it.
+        let list;
+        let raw = RawEs(match self {
+            EdgeSelector::All => unsafe {
+                igraph_ess_all(igraph_edgeorder_type_t_IGRAPH_EDGEORDER_ID)
+            },
+            EdgeSelector::Single(eid) => unsafe { igraph_ess_1(eid) },
+            EdgeSelector::List(ids) => {
+                list = view(ids);
+                unsafe { igraph_ess_vector(&list) }
+            }
+            EdgeSelector::Range(start, end) => unsafe { igraph_ess_range(start, end) },
+            EdgeSelector::Incident(vid, mode) => init_raw(|es| unsafe {
+                igraph_es_incident(es, vid, mode.to_raw(), igraph_loops_t_IGRAPH_LOOPS_ONCE)
+            })?,
+            EdgeSelector::Pairs(pairs, directed) => {
+                let flat: Vec<i64> = pairs.iter().flat_map(|&(from, to)| [from, to]).collect();
+                let v = VectorInt::from_slice(&flat)?;
+                // igraph_es_pairs keeps its own copy of the vector.
+                init_raw(|es| unsafe { igraph_es_pairs(es, v.as_ptr(), directed) })?
+            }
+            EdgeSelector::Path(vids, directed) => {
+                // igraph_es_path keeps its own copy of the vector.
+                let path = view(vids);
+                init_raw(|es| unsafe { igraph_es_path(es, &path, directed) })?
+            }
+            EdgeSelector::AllBetween(from, to, directed) => {
+                init_raw(|es| unsafe { igraph_es_all_between(es, from, to, directed) })?
+            }
+        });
+        f(raw.0)
+    }
+}
@@ -1,5 +1,9 @@
+use std::ops::ControlFlow;
+
 use igraph_sys::*;
 
+use crate::graph::Graph;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NeighborMode {
     Out,
@@ -120,3 +124,358 @@ impl ToUndirectedMode {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlissSplitHeuristic {
+    First,
+    FirstSmallest,
+    FirstLargest,
+    FirstMaxConnected,
+    FirstSmallestMaxConnected,
+    FirstLargestMaxConnected,
+}
+
+impl BlissSplitHeuristic {
+    pub(crate) fn to_raw(self) -> igraph_bliss_sh_t {
+        match self {
+            BlissSplitHeuristic::First => igraph_bliss_sh_t_IGRAPH_BLISS_F,
+            BlissSplitHeuristic::FirstSmallest => igraph_bliss_sh_t_IGRAPH_BLISS_FS,
+            BlissSplitHeuristic::FirstLargest => igraph_bliss_sh_t_IGRAPH_BLISS_FL,
+            BlissSplitHeuristic::FirstMaxConnected => igraph_bliss_sh_t_IGRAPH_BLISS_FM,
+            BlissSplitHeuristic::FirstSmallestMaxConnected => igraph_bliss_sh_t_IGRAPH_BLISS_FSM,
+            BlissSplitHeuristic::FirstLargestMaxConnected => igraph_bliss_sh_t_IGRAPH_BLISS_FLM,
+        }
+    }
+}
+
+/// Search statistics reported by the BLISS algorithm.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlissInfo {
+    pub nof_nodes: u64,
+    pub nof_leaf_nodes: u64,
+    pub nof_bad_nodes: u64,
+    pub nof_canupdates: u64,
+    pub nof_generators: u64,
+    pub max_level: u64,
+    /// Size of the automorphism group as a decimal string, since it can
+    /// easily exceed the range of any integer type.
+    pub group_size: String,
+}
+
+impl BlissInfo {
+    /// Convert a raw info struct, taking ownership of its `group_size` string.
+    ///
+    /// # Safety
+    /// `raw` must be zero-initialized or filled in by an igraph BLISS function,
+    /// and must not be used again afterwards.
+    pub(crate) unsafe fn from_raw(raw: igraph_bliss_info_t) -> Self {
+        let group_size = if raw.group_size.is_null() {
+            String::new()
+        } else {
+            unsafe {
+                let s = std::ffi::CStr::from_ptr(raw.group_size)
+                    .to_string_lossy()
+                    .into_owned();
+                igraph_free(raw.group_size.cast());
+                s
+            }
+        };
+        Self {
+            nof_nodes: raw.nof_nodes,
+            nof_leaf_nodes: raw.nof_leaf_nodes,
+            nof_bad_nodes: raw.nof_bad_nodes,
+            nof_canupdates: raw.nof_canupdates,
+            nof_generators: raw.nof_generators,
+            max_level: raw.max_level,
+            group_size,
+        }
+    }
+}
+
+/// Vertex and edge colors restricting which elements VF2 may match.
+///
+/// Index `1` refers to the first (target) graph and `2` to the second
+/// (pattern) graph. A vertex or edge is only matched to one of equal color;
+/// `None` leaves that side uncolored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Vf2Colors<'a> {
+    pub vertex1: Option<&'a [i64]>,
+    pub vertex2: Option<&'a [i64]>,
+    pub edge1: Option<&'a [i64]>,
+    pub edge2: Option<&'a [i64]>,
+}
+
+impl<'a> Vf2Colors<'a> {
+    /// Colors for the vertices of both graphs only.
+    pub fn vertices(vertex1: &'a [i64], vertex2: &'a [i64]) -> Self {
+        Self {
+            vertex1: Some(vertex1),
+            vertex2: Some(vertex2),
+            ..Self::default()
+        }
+    }
+
+    /// Colors for the edges of both graphs only.
+    pub fn edges(edge1: &'a [i64], edge2: &'a [i64]) -> Self {
+        Self {
+            edge1: Some(edge1),
+            edge2: Some(edge2),
+            ..Self::default()
+        }
+    }
+
+    /// Colors for the vertices and edges of both graphs.
+    pub fn vertices_and_edges(
+        vertex1: &'a [i64],
+        vertex2: &'a [i64],
+        edge1: &'a [i64],
+        edge2: &'a [i64],
+    ) -> Self {
+        Self {
+            vertex1: Some(vertex1),
+            vertex2: Some(vertex2),
+            edge1: Some(edge1),
+            edge2: Some(edge2),
+        }
+    }
+}
+
+/// Closure deciding whether vertex (or edge) `i` of the first graph may be
+/// matched to vertex (or edge) `j` of the second graph during VF2 matching.
+///
+/// It is called as `f(graph1, graph2, i, j)`.
+pub type Vf2CompatFn<'a> = dyn FnMut(&Graph, &Graph, i64, i64) -> bool + 'a;
+
+/// Closure receiving each VF2 match as `(map12, map21)`.
+///
+/// Return [`ControlFlow::Break`] to stop the search early.
+pub(crate) type Vf2HandlerFn<'a> = dyn FnMut(&[i64], &[i64]) -> ControlFlow<()> + 'a;
+
+/// Dyad census of a directed graph: the number of vertex pairs connected in
+/// both directions, in one direction only, or not at all.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DyadCensus {
+    pub mutual: f64,
+    pub asymmetric: f64,
+    pub null: f64,
+}
+
+/// Triad census of a directed graph, using the standard MAN labels
+/// (number of Mutual, Asymmetric and Null dyads, plus a direction suffix).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TriadCensus {
+    pub t003: f64,
+    pub t012: f64,
+    pub t102: f64,
+    pub t021d: f64,
+    pub t021u: f64,
+    pub t021c: f64,
+    pub t111d: f64,
+    pub t111u: f64,
+    pub t030t: f64,
+    pub t030c: f64,
+    pub t201: f64,
+    pub t120d: f64,
+    pub t120u: f64,
+    pub t120c: f64,
+    pub t210: f64,
+    pub t300: f64,
+}
+
+impl TriadCensus {
+    /// Build from igraph's result vector, which lists the 16 classes in
+    /// the order of the struct fields.
+    pub(crate) fn from_slice(v: &[f64]) -> Self {
+        Self {
+            t003: v[0],
+            t012: v[1],
+            t102: v[2],
+            t021d: v[3],
+            t021u: v[4],
+            t021c: v[5],
+            t111d: v[6],
+            t111u: v[7],
+            t030t: v[8],
+            t030c: v[9],
+            t201: v[10],
+            t120d: v[11],
+            t120u: v[12],
+            t120c: v[13],
+            t210: v[14],
+            t300: v[15],
+        }
+    }
+}
+
+/// A cycle, given both as its vertex sequence and as the edges joining
+/// consecutive vertices (the last edge closes the cycle).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    pub vertices: Vec<i64>,
+    pub edges: Vec<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FasAlgorithm {
+    ExactIp,
+    ExactIpCg,
+    ExactIpTi,
+    ApproxEades,
+}
+
+impl FasAlgorithm {
+    pub(crate) fn to_raw(self) -> igraph_fas_algorithm_t {
+        match self {
+            FasAlgorithm::ExactIp => igraph_fas_algorithm_t_IGRAPH_FAS_EXACT_IP,
+            FasAlgorithm::ExactIpCg => igraph_fas_algorithm_t_IGRAPH_FAS_EXACT_IP_CG,
+            FasAlgorithm::ExactIpTi => igraph_fas_algorithm_t_IGRAPH_FAS_EXACT_IP_TI,
+            FasAlgorithm::ApproxEades => igraph_fas_algorithm_t_IGRAPH_FAS_APPROX_EADES,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MstAlgorithm {
+    Automatic,
+    Unweighted,
+    Prim,
+    Kruskal,
+}
+
+impl MstAlgorithm {
+    pub(crate) fn to_raw(self) -> igraph_mst_algorithm_t {
+        match self {
+            MstAlgorithm::Automatic => igraph_mst_algorithm_t_IGRAPH_MST_AUTOMATIC,
+            MstAlgorithm::Unweighted => igraph_mst_algorithm_t_IGRAPH_MST_UNWEIGHTED,
+            MstAlgorithm::Prim => igraph_mst_algorithm_t_IGRAPH_MST_PRIM,
+            MstAlgorithm::Kruskal => igraph_mst_algorithm_t_IGRAPH_MST_KRUSKAL,
+        }
+    }
+}
+
+/// Result of a breadth-first search.
+///
+/// `order` lists the visited vertices in the order they were reached. The
+/// other vectors are indexed by vertex id: `rank` is the position in
+/// `order`, `parents` the vertex the search came from (-1 for roots),
+/// `pred`/`succ` the vertex visited just before/after (-1 if none) and
+/// `dist` the distance from the root. Unreached vertices have negative
+/// entries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BfsResult {
+    pub order: Vec<i64>,
+    pub rank: Vec<i64>,
+    pub parents: Vec<i64>,
+    pub pred: Vec<i64>,
+    pub succ: Vec<i64>,
+    pub dist: Vec<i64>,
+}
+
+/// Result of a depth-first search.
+///
+/// `order` lists the vertices as they were discovered and `order_out` as
+/// their subtrees were finished. `parents` (-1 for roots) and `dist` are
+/// indexed by vertex id, with negative entries for unreached vertices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DfsResult {
+    pub order: Vec<i64>,
+    pub order_out: Vec<i64>,
+    pub parents: Vec<i64>,
+    pub dist: Vec<i64>,
+}
+
+/// A vertex reached by a breadth-first search, as passed to the visitor.
+///
+/// `pred` and `succ` are the vertices visited just before and after this
+/// one in the same component (-1 if none), `rank` its position in the
+/// visiting order and `dist` its distance from the root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BfsVisit {
+    pub vid: i64,
+    pub pred: i64,
+    pub succ: i64,
+    pub rank: i64,
+    pub dist: i64,
+}
+
+/// Event reported to a depth-first search visitor, with the vertex and its
+/// distance from the root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DfsEvent {
+    /// The vertex was reached for the first time.
+    Discover { vid: i64, dist: i64 },
+    /// All vertices reachable through the vertex have been visited.
+    Finish { vid: i64, dist: i64 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransitivityMode {
+    Nan,
+    Zero,
+}
+
+impl TransitivityMode {
+    pub(crate) fn to_raw(self) -> igraph_transitivity_mode_t {
+        match self {
+            TransitivityMode::Nan => igraph_transitivity_mode_t_IGRAPH_TRANSITIVITY_NAN,
+            TransitivityMode::Zero => igraph_transitivity_mode_t_IGRAPH_TRANSITIVITY_ZERO,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReciprocityMode {
+    Default,
+    Ratio,
+}
+
+impl ReciprocityMode {
+    pub(crate) fn to_raw(self) -> igraph_reciprocity_t {
+        match self {
+            ReciprocityMode::Default => igraph_reciprocity_t_IGRAPH_RECIPROCITY_DEFAULT,
+            ReciprocityMode::Ratio => igraph_reciprocity_t_IGRAPH_RECIPROCITY_RATIO,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdjacencyType {
+    Upper,
+    Lower,
+    Both,
+}
+
+impl AdjacencyType {
+    pub(crate) fn to_raw(self) -> igraph_get_adjacency_t {
+        match self {
+            AdjacencyType::Upper => igraph_get_adjacency_t_IGRAPH_GET_ADJACENCY_UPPER,
+            AdjacencyType::Lower => igraph_get_adjacency_t_IGRAPH_GET_ADJACENCY_LOWER,
+            AdjacencyType::Both => igraph_get_adjacency_t_IGRAPH_GET_ADJACENCY_BOTH,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LaplacianNormalization {
+    Unnormalized,
+    Symmetric,
+    Left,
+    Right,
+}
+
+impl LaplacianNormalization {
+    pub(crate) fn to_raw(self) -> igraph_laplacian_normalization_t {
+        match self {
+            LaplacianNormalization::Unnormalized => {
+                igraph_laplacian_normalization_t_IGRAPH_LAPLACIAN_UNNORMALIZED
+            }
+            LaplacianNormalization::Symmetric => {
+                igraph_laplacian_normalization_t_IGRAPH_LAPLACIAN_SYMMETRIC
+            }
+            LaplacianNormalization::Left => igraph_laplacian_normalization_t_IGRAPH_LAPLACIAN_LEFT,
+            LaplacianNormalization::Right => {
+                igraph_laplacian_normalization_t_IGRAPH_LAPLACIAN_RIGHT
+            }
+        }
+    }
+}
@@ -1,4 +1,7 @@
+use std::fmt;
 use std::mem::MaybeUninit;
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::slice::SliceIndex;
 
 use igraph_sys::*;
 
@@ -30,14 +33,19 @@ impl VectorInt {
         }
     }
 
+    /// Copy `data` into a new vector in a single bulk copy.
     pub fn from_slice(data: &[i64]) -> Result<Self> {
-        let mut v = Self::new()?;
-        for &val in data {
-            unsafe {
-                check(igraph_vector_int_push_back(&mut v.inner, val))?;
-            }
+        let mut v = MaybeUninit::uninit();
+        unsafe {
+            check(igraph_vector_int_init_array(
+                v.as_mut_ptr(),
+                data.as_ptr(),
+                data.len() as i64,
+            ))?;
+            Ok(Self {
+                inner: v.assume_init(),
+            })
         }
-        Ok(v)
     }
 
     pub fn len(&self) -> i64 {
@@ -48,17 +56,28 @@ impl VectorInt {
         self.len() == 0
     }
 
+    /// Return the element at `pos`, panicking if it is out of range.
     pub fn get(&self, pos: i64) -> i64 {
-        unsafe { igraph_vector_int_get(&self.inner, pos) }
+        self.as_slice()[pos as usize]
     }
 
+    /// Overwrite the element at `pos`, panicking if it is out of range.
     pub fn set(&mut self, pos: i64, value: i64) {
-        unsafe { igraph_vector_int_set(&mut self.inner, pos, value) }
+        self.as_mut_slice()[pos as usize] = value;
+    }
+
+    /// Borrow the elements in place.
+    pub fn as_slice(&self) -> &[i64] {
+        unsafe { raw_slice(self.inner.stor_begin, self.inner.end) }
+    }
+
+    /// Borrow the elements in place for modification.
+    pub fn as_mut_slice(&mut self) -> &mut [i64] {
+        unsafe { raw_slice_mut(self.inner.stor_begin, self.inner.end) }
     }
 
     pub fn to_vec(&self) -> Vec<i64> {
-        let n = self.len();
-        (0..n).map(|i| self.get(i)).collect()
+        self.as_slice().to_vec()
     }
 
     pub(crate) fn as_ptr(&self) -> *const igraph_vector_int_t {
@@ -70,6 +89,137 @@ impl VectorInt {
     }
 }
 
+/// Borrow the contents of an igraph integer vector as a slice.
+///
+/// # Safety
+/// `v` must point to a valid, initialized `igraph_vector_int_t` that is
+/// neither modified nor destroyed while the returned slice is alive.
+pub(crate) unsafe fn vector_int_slice<'a>(v: *const igraph_vector_int_t) -> &'a [i64] {
+    unsafe { raw_slice((*v).stor_begin, (*v).end) }
+}
+
+/// Borrow the igraph storage `begin..end` as a slice.
+///
+/// # Safety
+/// `begin..end` must be the initialized part of a live igraph vector, or
+/// `begin` must be null.
+pub(crate) unsafe fn raw_slice<'a, T>(begin: *const T, end: *const T) -> &'a [T] {
+    if begin.is_null() {
+        return &[];
+    }
+    unsafe { std::slice::from_raw_parts(begin, end.offset_from(begin) as usize) }
+}
+
+/// Mutable counterpart of [`raw_slice`].
+///
+/// # Safety
+/// As for [`raw_slice`], and the storage must not be aliased while the
+/// returned slice is alive.
+pub(crate) unsafe fn raw_slice_mut<'a, T>(begin: *mut T, end: *mut T) -> &'a mut [T] {
+    if begin.is_null() {
+        return &mut [];
+    }
+    unsafe { std::slice::from_raw_parts_mut(begin, end.offset_from(begin) as usize) }
+}
+
+/// Implement the slice-like standard traits shared by `Vector` and
+/// `VectorInt` on top of `as_slice`.
+macro_rules! impl_slice_traits {
+    ($ty:ident, $elem:ty, $init_copy:ident) => {
+        impl Deref for $ty {
+            type Target = [$elem];
+
+            fn deref(&self) -> &[$elem] {
+                self.as_slice()
+            }
+        }
+
+        impl DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut [$elem] {
+                self.as_mut_slice()
+            }
+        }
+
+        impl<I: SliceIndex<[$elem]>> Index<I> for $ty {
+            type Output = I::Output;
+
+            fn index(&self, index: I) -> &I::Output {
+                &self.as_slice()[index]
+            }
+        }
+
+        impl<I: SliceIndex<[$elem]>> IndexMut<I> for $ty {
+            fn index_mut(&mut self, index: I) -> &mut I::Output {
+                &mut self.as_mut_slice()[index]
+            }
+        }
+
+        impl<'a> IntoIterator for &'a $ty {
+            type Item = &'a $elem;
+            type IntoIter = std::slice::Iter<'a, $elem>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.as_slice().iter()
+            }
+        }
+
+        impl<'a> IntoIterator for &'a mut $ty {
+            type Item = &'a mut $elem;
+            type IntoIter = std::slice::IterMut<'a, $elem>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.as_mut_slice().iter_mut()
+            }
+        }
+
+        impl IntoIterator for $ty {
+            type Item = $elem;
+            type IntoIter = std::vec::IntoIter<$elem>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.to_vec().into_iter()
+            }
+        }
+
+        impl FromIterator<$elem> for $ty {
+            fn from_iter<T: IntoIterator<Item = $elem>>(iter: T) -> Self {
+                let data: Vec<$elem> = iter.into_iter().collect();
+                Self::from_slice(&data).expect("failed to allocate igraph vector")
+            }
+        }
+
+        impl Clone for $ty {
+            fn clone(&self) -> Self {
+                let mut v = MaybeUninit::uninit();
+                unsafe {
+                    check($init_copy(v.as_mut_ptr(), &self.inner))
+                        .expect("failed to allocate igraph vector");
+                    Self {
+                        inner: v.assume_init(),
+                    }
+                }
+            }
+        }
+
+        impl fmt::Debug for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_list().entries(self.as_slice()).finish()
+            }
+        }
+
+        impl PartialEq for $ty {
+            fn eq(&self, other: &Self) -> bool {
+                self.as_slice() == other.as_slice()
+            }
+        }
+    };
+}
+
+impl_slice_traits!(VectorInt, i64, igraph_vector_int_init_copy);
+impl_slice_traits!(Vector, f64, igraph_vector_init_copy);
+
+impl Eq for VectorInt {}
+
 impl Drop for VectorInt {
     fn drop(&mut self) {
         unsafe { igraph_vector_int_destroy(&mut self.inner) }
@@ -106,14 +256,19 @@ impl Vector {
         }
     }
 
+    /// Copy `data` into a new vector in a single bulk copy.
     pub fn from_slice(data: &[f64]) -> Result<Self> {
-        let mut v = Self::new()?;
-        for &val in data {
-            unsafe {
-                check(igraph_vector_push_back(&mut v.inner, val))?;
-            }
+        let mut v = MaybeUninit::uninit();
+        unsafe {
+            check(igraph_vector_init_array(
+                v.as_mut_ptr(),
+                data.as_ptr(),
+                data.len() as i64,
+            ))?;
+            Ok(Self {
+                inner: v.assume_init(),
+            })
         }
-        Ok(v)
     }
 
     pub fn len(&self) -> i64 {
@@ -124,17 +279,28 @@ impl Vector {
         self.len() == 0
     }
 
+    /// Return the element at `pos`, panicking if it is out of range.
     pub fn get(&self, pos: i64) -> f64 {
-        unsafe { igraph_vector_get(&self.inner, pos) }
+        self.as_slice()[pos as usize]
     }
 
+    /// Overwrite the element at `pos`, panicking if it is out of range.
     pub fn set(&mut self, pos: i64, value: f64) {
-        unsafe { igraph_vector_set(&mut self.inner, pos, value) }
+        self.as_mut_slice()[pos as usize] = value;
+    }
+
+    /// Borrow the elements in place.
+    pub fn as_slice(&self) -> &[f64] {
+        unsafe { raw_slice(self.inner.stor_begin, self.inner.end) }
+    }
+
+    /// Borrow the elements in place for modification.
+    pub fn as_mut_slice(&mut self) -> &mut [f64] {
+        unsafe { raw_slice_mut(self.inner.stor_begin, self.inner.end) }
     }
 
     pub fn to_vec(&self) -> Vec<f64> {
-        let n = self.len();
-        (0..n).map(|i| self.get(i)).collect()
+        self.as_slice().to_vec()
     }
 
     pub(crate) fn as_ptr(&self) -> *const igraph_vector_t {
@@ -156,6 +322,54 @@ impl Drop for Vector {
 // state. Safe to move between threads.
 unsafe impl Send for Vector {}
 
+/// Safe wrapper around `igraph_vector_bool_t` (vector of `bool`).
+pub struct VectorBool {
+    pub(crate) inner: igraph_vector_bool_t,
+}
+
+impl VectorBool {
+    pub fn new() -> Result<Self> {
+        let mut v = MaybeUninit::uninit();
+        unsafe {
+            check(igraph_vector_bool_init(v.as_mut_ptr(), 0))?;
+            Ok(Self {
+                inner: v.assume_init(),
+            })
+        }
+    }
+
+    pub fn len(&self) -> i64 {
+        unsafe { igraph_vector_bool_size(&self.inner) }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, pos: i64) -> bool {
+        unsafe { igraph_vector_bool_get(&self.inner, pos) }
+    }
+
+    pub fn to_vec(&self) -> Vec<bool> {
+        let n = self.len();
+        (0..n).map(|i| self.get(i)).collect()
+    }
+
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut igraph_vector_bool_t {
+        &mut self.inner
+    }
+}
+
+impl Drop for VectorBool {
+    fn drop(&mut self) {
+        unsafe { igraph_vector_bool_destroy(&mut self.inner) }
+    }
+}
+
+// Safety: VectorBool is a self-contained heap allocation with no shared
+// global state. Safe to move between threads.
+unsafe impl Send for VectorBool {}
+
 /// Safe wrapper around `igraph_vector_int_list_t` (list of `VectorInt`).
 pub struct VectorIntList {
     pub(crate) inner: igraph_vector_int_list_t,
@@ -172,6 +386,21 @@ impl VectorIntList {
         }
     }
 
+    /// Build a list from a slice of integer sequences.
+    pub fn from_slices<T: AsRef<[i64]>>(data: &[T]) -> Result<Self> {
+        let mut list = Self::new()?;
+        for item in data {
+            let v = VectorInt::from_slice(item.as_ref())?;
+            unsafe {
+                check(igraph_vector_int_list_push_back_copy(
+                    &mut list.inner,
+                    v.as_ptr(),
+                ))?;
+            }
+        }
+        Ok(list)
+    }
+
     pub fn len(&self) -> i64 {
         unsafe { igraph_vector_int_list_size(&self.inner) }
     }
@@ -182,11 +411,7 @@ impl VectorIntList {
 
     /// Get the `pos`-th vector as a `Vec<i64>`.
     pub fn get(&self, pos: i64) -> Vec<i64> {
-        unsafe {
-            let vptr = igraph_vector_int_list_get_ptr(&self.inner, pos);
-            let n = igraph_vector_int_size(&*vptr);
-            (0..n).map(|i| igraph_vector_int_get(&*vptr, i)).collect()
-        }
+        unsafe { vector_int_slice(igraph_vector_int_list_get_ptr(&self.inner, pos)).to_vec() }
     }
 
     /// Convert the entire list to a `Vec<Vec<i64>>`.
@@ -195,6 +420,10 @@ impl VectorIntList {
         (0..n).map(|i| self.get(i)).collect()
     }
 
+    pub(crate) fn as_ptr(&self) -> *const igraph_vector_int_list_t {
+        &self.inner
+    }
+
     pub(crate) fn as_mut_ptr(&mut self) -> *mut igraph_vector_int_list_t {
         &mut self.inner
     }
@@ -1,4 +1,6 @@
+use std::ops::ControlFlow;
 use std::thread;
+use std::time::Duration;
 
 use igraph::*;
 
@@ -34,6 +36,61 @@ fn test_matrix_dimensions() {
     assert_eq!(m.ncol(), 4);
 }
 
+#[test]
+fn test_vector_slice_access() {
+    let mut v = VectorInt::from_slice(&[3, 1, 2]).unwrap();
+    assert_eq!(v.as_slice(), &[3, 1, 2]);
+    v.sort();
+    v[0] = 10;
+    assert_eq!(&v[..], &[10, 2, 3]);
+    assert_eq!(v.iter().sum::<i64>(), 15);
+    for x in &mut v {
+        *x *= 2;
+    }
+    assert_eq!(v.get(2), 6);
+    assert_eq!(format!("{v:?}"), "[20, 4, 6]");
+
+    let e = Vector::from_slice(&[]).unwrap();
+    assert!(e.is_empty());
+    assert_eq!(e.as_slice(), &[] as &[f64]);
+}
+
+#[test]
+fn test_vector_std_traits() {
+    let v: Vector = (0..4).map(f64::from).collect();
+    let w = v.clone();
+    assert_eq!(v, w);
+    assert_eq!(w.into_iter().collect::<Vec<_>>(), vec![0.0, 1.0, 2.0, 3.0]);
+
+    let mut a: VectorInt = [1, 2, 3].into_iter().collect();
+    let b = a.clone();
+    a.set(0, 5);
+    assert_ne!(a, b);
+    assert_eq!(b.to_vec(), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_matrix_column_major() {
+    let mut m = Matrix::from_column_major(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+    assert_eq!(m.get(0, 1), 3.0);
+    assert_eq!(m.get(1, 2), 6.0);
+    assert_eq!(
+        m.to_vec_of_vecs(),
+        vec![vec![1.0, 3.0, 5.0], vec![2.0, 4.0, 6.0]]
+    );
+    m.as_mut_slice()[1] = 0.0;
+    assert_eq!(m.get(1, 0), 0.0);
+    assert_eq!(m.clone(), m);
+    assert!(matches!(
+        Matrix::from_column_major(2, 2, &[1.0]),
+        Err(Error::InvalidValue)
+    ));
+    assert!(matches!(
+        Matrix::from_column_major(1 << 32, 1 << 32, &[]),
+        Err(Error::InvalidValue)
+    ));
+}
+
 // === Graph construction tests ===
 
 #[test]
@@ -80,7 +137,9 @@ fn test_neighbors() {
 #[test]
 fn test_degree() {
     let g = Graph::from_edges(&[(0, 1), (0, 2), (1, 2)], 3, false).unwrap();
-    let deg = g.degree(NeighborMode::All, Loops::Twice).unwrap();
+    let deg = g
+        .degree(VertexSelector::All, NeighborMode::All, Loops::Twice)
+        .unwrap();
     assert_eq!(deg, vec![2, 2, 2]);
 }
 
@@ -95,6 +154,166 @@ fn test_edge() {
     assert_eq!(to, 3);
 }
 
+#[test]
+fn test_incident() {
+    let g = Graph::from_edges(&[(0, 1), (1, 2), (2, 0), (1, 1)], 3, true).unwrap();
+    let mut out = g.incident(1, NeighborMode::Out, Loops::Once).unwrap();
+    out.sort();
+    assert_eq!(out, vec![1, 3]);
+    let mut all = g.incident(1, NeighborMode::All, Loops::Twice).unwrap();
+    all.sort();
+    assert_eq!(all, vec![0, 1, 3, 3]);
+    let mut no_loops = g.incident(1, NeighborMode::All, Loops::No).unwrap();
+    no_loops.sort();
+    assert_eq!(no_loops, vec![0, 1]);
+}
+
+#[test]
+fn test_get_eid() {
+    let g = Graph::from_edges(&[(0, 1), (1, 2), (0, 1)], 3, true).unwrap();
+    assert!(matches!(g.get_eid(0, 1, true, false).unwrap(), Some(0 | 2)));
+    assert_eq!(g.get_eid(2, 1, true, false).unwrap(), None);
+    assert_eq!(g.get_eid(2, 1, false, false).unwrap(), Some(1));
+    assert_eq!(g.get_eid(2, 0, false, true), Err(Error::InvalidValue));
+
+    let eids = g.get_eids(&[(1, 2), (2, 0), (2, 1)], false, false).unwrap();
+    assert_eq!(eids, vec![Some(1), None, Some(1)]);
+    assert_eq!(
+        g.get_eids(&[(1, 2), (2, 0)], false, true),
+        Err(Error::InvalidValue)
+    );
+
+    let mut multi = g.get_all_eids_between(0, 1, true).unwrap();
+    multi.sort();
+    assert_eq!(multi, vec![0, 2]);
+    assert!(g.get_all_eids_between(1, 0, true).unwrap().is_empty());
+    assert_eq!(g.get_all_eids_between(2, 1, false).unwrap(), vec![1]);
+}
+
+#[test]
+fn test_edges_bulk() {
+    let g = Graph::from_edges(&[(0, 1), (1, 2), (2, 3)], 4, true).unwrap();
+    assert_eq!(
+        g.edge_endpoints(EdgeSelector::List(&[2, 0])).unwrap(),
+        vec![(2, 3), (0, 1)]
+    );
+    assert!(
+        g.edge_endpoints(EdgeSelector::List(&[]))
+            .unwrap()
+            .is_empty()
+    );
+    let (eids, pairs): (Vec<i64>, Vec<(i64, i64)>) =
+        (0..g.ecount()).map(|e| (e, g.edge(e).unwrap())).unzip();
+    assert_eq!(g.edge_endpoints(EdgeSelector::List(&eids)).unwrap(), pairs);
+}
+
+#[test]
+fn test_simple_loop_multiple_predicates() {
+    let simple = Graph::ring(4, false, false, true).unwrap();
+    assert!(simple.is_simple(true).unwrap());
+    assert!(!simple.has_loop().unwrap());
+    assert!(!simple.has_multiple().unwrap());
+    assert_eq!(simple.count_loops().unwrap(), 0);
+
+    let g = Graph::from_edges(&[(0, 1), (1, 0), (0, 1), (2, 2), (1, 2)], 3, true).unwrap();
+    assert!(!g.is_simple(true).unwrap());
+    assert!(g.has_loop().unwrap());
+    assert!(g.has_multiple().unwrap());
+    assert!(g.has_mutual(false).unwrap());
+    assert_eq!(g.count_loops().unwrap(), 1);
+    assert_eq!(
+        g.is_loop(EdgeSelector::All).unwrap(),
+        vec![false, false, false, true, false]
+    );
+    assert_eq!(
+        g.is_multiple(EdgeSelector::All).unwrap(),
+        vec![false, false, true, false, false]
+    );
+    assert_eq!(
+        g.count_multiple(EdgeSelector::All).unwrap(),
+        vec![2, 1, 2, 1, 1]
+    );
+    assert_eq!(
+        g.count_multiple(EdgeSelector::List(&[2, 4])).unwrap(),
+        vec![2, 1]
+    );
+    assert_eq!(
+        g.is_mutual(EdgeSelector::All, false).unwrap(),
+        vec![true, true, true, false, false]
+    );
+    assert_eq!(
+        g.is_mutual(EdgeSelector::Single(3), true).unwrap(),
+        vec![true]
+    );
+
+    let ab = Graph::from_edges(&[(0, 1), (1, 0)], 2, true).unwrap();
+    assert!(ab.is_simple(true).unwrap());
+    assert!(!ab.is_simple(false).unwrap());
+}
+
+#[test]
+fn test_clique_and_independence_predicates() {
+    let full = Graph::full(4, false, false).unwrap();
+    assert!(full.is_complete().unwrap());
+    let ring = Graph::ring(5, false, false, true).unwrap();
+    assert!(!ring.is_complete().unwrap());
+    assert!(ring.is_clique(&[0, 1], false).unwrap());
+    assert!(!ring.is_clique(&[0, 1, 2], false).unwrap());
+    assert!(ring.is_independent_vertex_set(&[0, 2]).unwrap());
+    assert!(!ring.is_independent_vertex_set(&[0, 1]).unwrap());
+
+    let one_way = Graph::from_edges(&[(0, 1)], 2, true).unwrap();
+    assert!(one_way.is_clique(&[0, 1], false).unwrap());
+    assert!(!one_way.is_clique(&[0, 1], true).unwrap());
+}
+
+#[test]
+fn test_chordal_and_perfect() {
+    let c4 = Graph::ring(4, false, false, true).unwrap();
+    assert!(!c4.is_chordal().unwrap());
+    assert!(c4.is_perfect().unwrap());
+    let c5 = Graph::ring(5, false, false, true).unwrap();
+    assert!(!c5.is_perfect().unwrap());
+    let directed = Graph::ring(4, true, false, true).unwrap();
+    assert_eq!(directed.is_perfect(), Err(Error::InvalidValue));
+    let multi = Graph::from_edges(&[(0, 1), (0, 1), (1, 2)], 3, false).unwrap();
+    assert_eq!(multi.is_perfect(), Err(Error::InvalidValue));
+
+    let mut chorded = Graph::ring(4, false, false, true).unwrap();
+    chorded.add_edges(&[(0, 2)]).unwrap();
+    assert!(chorded.is_chordal().unwrap());
+    let (alpha, alpham1) = chorded.maximum_cardinality_search().unwrap();
+    assert_eq!(alpha.len(), 4);
+    for (v, &rank) in alpha.iter().enumerate() {
+        assert_eq!(alpham1[rank as usize], v as i64);
+    }
+}
+
+#[test]
+fn test_density_degree_reciprocity() {
+    let full = Graph::full(4, false, false).unwrap();
+    assert!((full.density(None, false).unwrap() - 1.0).abs() < 1e-10);
+    let ring = Graph::ring(4, false, false, true).unwrap();
+    assert!((ring.density(None, false).unwrap() - 4.0 / 6.0).abs() < 1e-10);
+    assert_eq!(
+        ring.maxdegree(VertexSelector::All, NeighborMode::All, Loops::Twice)
+            .unwrap(),
+        2
+    );
+    assert!((ring.mean_degree(true).unwrap() - 2.0).abs() < 1e-10);
+
+    let g = Graph::from_edges(&[(0, 1), (1, 0), (1, 2)], 3, true).unwrap();
+    let r = g.reciprocity(true, ReciprocityMode::Default).unwrap();
+    assert!((r - 2.0 / 3.0).abs() < 1e-10);
+    let r = g.reciprocity(true, ReciprocityMode::Ratio).unwrap();
+    assert!((r - 0.5).abs() < 1e-10);
+    assert_eq!(
+        g.maxdegree(VertexSelector::All, NeighborMode::Out, Loops::Twice)
+            .unwrap(),
+        2
+    );
+}
+
 // === Graph mutation tests ===
 
 #[test]
@@ -167,11 +386,13 @@ fn test_barabasi() {
 #[test]
 fn test_distances() {
     let g = Graph::from_edges(&[(0, 1), (1, 2), (2, 3)], 4, false).unwrap();
-    let dist = g.distances(NeighborMode::All).unwrap();
-    assert_eq!(dist[0][0], 0.0);
-    assert_eq!(dist[0][1], 1.0);
-    assert_eq!(dist[0][2], 2.0);
-    assert_eq!(dist[0][3], 3.0);
+    let dist = g
+        .distances(VertexSelector::All, VertexSelector::All, NeighborMode::All)
+        .unwrap();
+    assert_eq!(dist[(0, 0)], 0.0);
+    assert_eq!(dist[(0, 1)], 1.0);
+    assert_eq!(dist[(0, 2)], 2.0);
+    assert_eq!(dist[(0, 3)], 3.0);
 }
 
 #[test]
@@ -186,7 +407,7 @@ fn test_diameter() {
 #[test]
 fn test_betweenness() {
     let g = Graph::from_edges(&[(0, 1), (1, 2), (2, 3)], 4, false).unwrap();
-    let bt = g.betweenness(false).unwrap();
+    let bt = g.betweenness(VertexSelector::All, false).unwrap();
     assert_eq!(bt.len(), 4);
     // Middle vertices should have higher betweenness
     assert!(bt[1] > bt[0]);
@@ -196,7 +417,7 @@ fn test_betweenness() {
 #[test]
 fn test_closeness() {
     let g = Graph::full(5, false, false).unwrap();
-    let cl = g.closeness(NeighborMode::All).unwrap();
+    let cl = g.closeness(VertexSelector::All, NeighborMode::All).unwrap();
     assert_eq!(cl.len(), 5);
     // In a complete graph, all closeness values are equal
     for c in &cl {
@@ -207,7 +428,7 @@ fn test_closeness() {
 #[test]
 fn test_pagerank() {
     let g = Graph::full(5, false, false).unwrap();
-    let pr = g.pagerank(0.85).unwrap();
+    let pr = g.pagerank(VertexSelector::All, 0.85).unwrap();
     assert_eq!(pr.len(), 5);
     let sum: f64 = pr.iter().sum();
     assert!((sum - 1.0).abs() < 1e-6);
@@ -325,17 +546,19 @@ fn test_full_workflow() {
     assert!(g.is_connected(Connectedness::Weak).unwrap());
 
     // Compute centrality
-    let bt = g.betweenness(false).unwrap();
+    let bt = g.betweenness(VertexSelector::All, false).unwrap();
     assert_eq!(bt.len(), 5);
 
     // Compute PageRank
-    let pr = g.pagerank(0.85).unwrap();
+    let pr = g.pagerank(VertexSelector::All, 0.85).unwrap();
     let sum: f64 = pr.iter().sum();
     assert!((sum - 1.0).abs() < 1e-6);
 
     // Get distances
-    let dist = g.distances(NeighborMode::All).unwrap();
-    assert_eq!(dist.len(), 5);
+    let dist = g
+        .distances(VertexSelector::All, VertexSelector::All, NeighborMode::All)
+        .unwrap();
+    assert_eq!(dist.nrow(), 5);
 
     // Compute diameter
     let d = g.diameter(false).unwrap();
@@ -382,3 +605,1784 @@ fn test_multiple_graphs_in_threads() {
         assert_eq!(h.join().unwrap(), expected);
     }
 }
+
+// === Isomorphism tests ===
+
+#[test]
+fn test_isomorphic() {
+    let ring = Graph::ring(5, false, false, true).unwrap();
+    let shuffled = Graph::from_edges(&[(0, 2), (2, 4), (4, 1), (1, 3), (3, 0)], 5, false).unwrap();
+    let star = Graph::star(5, StarMode::Undirected, 0).unwrap();
+    assert!(ring.isomorphic(&shuffled).unwrap());
+    assert!(!ring.isomorphic(&star).unwrap());
+}
+
+#[test]
+fn test_isomorphic_vf2_maps() {
+    let g1 = Graph::from_edges(&[(0, 1), (1, 2)], 3, false).unwrap();
+    let g2 = Graph::from_edges(&[(1, 0), (0, 2)], 3, false).unwrap();
+    let (map12, map21) = g1
+        .isomorphic_vf2(&g2, &Vf2Colors::default(), None, None)
+        .unwrap()
+        .unwrap();
+    // The center of the path must map to the center.
+    assert_eq!(map12[1], 0);
+    assert_eq!(map21[0], 1);
+
+    let g3 = Graph::full(3, false, false).unwrap();
+    assert!(
+        g1.isomorphic_vf2(&g3, &Vf2Colors::default(), None, None)
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn test_count_isomorphisms_vf2() {
+    // The automorphism group of a 5-cycle is the dihedral group of order 10.
+    let ring = Graph::ring(5, false, false, true).unwrap();
+    assert_eq!(
+        ring.count_isomorphisms_vf2(&ring, &Vf2Colors::default(), None, None)
+            .unwrap(),
+        10
+    );
+    assert_eq!(
+        ring.get_isomorphisms_vf2(&ring, &Vf2Colors::default(), None, None)
+            .unwrap()
+            .len(),
+        10
+    );
+}
+
+#[test]
+fn test_bliss_automorphisms() {
+    let g = Graph::famous("Petersen").unwrap();
+    let info = g
+        .count_automorphisms_bliss(None, BlissSplitHeuristic::FirstLargestMaxConnected)
+        .unwrap();
+    assert_eq!(info.group_size, "120");
+    let generators = g
+        .automorphism_group_bliss(None, BlissSplitHeuristic::FirstLargestMaxConnected)
+        .unwrap();
+    assert!(!generators.is_empty());
+
+    // Coloring one vertex differently restricts the group to its stabilizer.
+    let mut colors = vec![0; 10];
+    colors[0] = 1;
+    let info = g
+        .count_automorphisms_bliss(Some(&colors), BlissSplitHeuristic::First)
+        .unwrap();
+    assert_eq!(info.group_size, "12");
+}
+
+#[test]
+fn test_isomorphic_bliss_colors() {
+    let g1 = Graph::from_edges(&[(0, 1), (1, 2)], 3, false).unwrap();
+    let g2 = Graph::from_edges(&[(2, 1), (1, 0)], 3, false).unwrap();
+    let sh = BlissSplitHeuristic::FirstLargest;
+    assert!(g1.isomorphic_bliss(&g2, None, None, sh).unwrap().is_some());
+    assert!(
+        g1.isomorphic_bliss(&g2, Some(&[0, 0, 1]), Some(&[0, 0, 1]), sh)
+            .unwrap()
+            .is_some()
+    );
+    // An endpoint colored 1 cannot map to the center colored 1.
+    assert!(
+        g1.isomorphic_bliss(&g2, Some(&[0, 0, 1]), Some(&[0, 1, 0]), sh)
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn test_canonical_form() {
+    let g1 = Graph::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0), (0, 4)], 5, false).unwrap();
+    let g2 = Graph::from_edges(&[(4, 3), (3, 2), (2, 1), (1, 4), (4, 0)], 5, false).unwrap();
+    let sh = BlissSplitHeuristic::FirstLargestMaxConnected;
+    let canonical = |g: &Graph| {
+        let (labeling, _info) = g.canonical_permutation_bliss(None, sh).unwrap();
+        let mut edges: Vec<_> = g
+            .permute_vertices(&labeling)
+            .unwrap()
+            .get_edgelist()
+            .unwrap()
+            .into_iter()
+            .map(|(a, b)| (a.min(b), a.max(b)))
+            .collect();
+        edges.sort();
+        edges
+    };
+    assert_eq!(canonical(&g1), canonical(&g2));
+}
+
+#[test]
+fn test_permute_vertices() {
+    let g = Graph::from_edges(&[(0, 1), (1, 2)], 3, true).unwrap();
+    let p = g.permute_vertices(&[2, 0, 1]).unwrap();
+    assert_eq!(p.get_edgelist().unwrap(), vec![(2, 0), (0, 1)]);
+}
+
+#[test]
+fn test_subisomorphisms_vf2_vertex_colors() {
+    // Propanol-like skeleton C-C-C-O, searching for a C-O bond.
+    let host = Graph::from_edges(&[(0, 1), (1, 2), (2, 3)], 4, false).unwrap();
+    let pattern = Graph::from_edges(&[(0, 1)], 2, false).unwrap();
+    assert_eq!(
+        host.count_subisomorphisms_vf2(&pattern, &Vf2Colors::default(), None, None)
+            .unwrap(),
+        6
+    );
+
+    let atoms = [6, 6, 6, 8];
+    let bond = [6, 8];
+    let colors = Vf2Colors::vertices(&atoms, &bond);
+    assert_eq!(
+        host.count_subisomorphisms_vf2(&pattern, &colors, None, None)
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        host.get_subisomorphisms_vf2(&pattern, &colors, None, None)
+            .unwrap(),
+        vec![vec![2, 3]]
+    );
+    let (_map12, map21) = host
+        .subisomorphic_vf2(&pattern, &colors, None, None)
+        .unwrap()
+        .unwrap();
+    assert_eq!(map21, vec![2, 3]);
+}
+
+#[test]
+fn test_subisomorphisms_vf2_edge_colors() {
+    // Bond orders: 0-1 single, 1-2 double, 2-3 single.
+    let host = Graph::from_edges(&[(0, 1), (1, 2), (2, 3)], 4, false).unwrap();
+    let pattern = Graph::from_edges(&[(0, 1)], 2, false).unwrap();
+    let orders = [1, 2, 1];
+    let double = [2];
+    let colors = Vf2Colors::edges(&orders, &double);
+    assert_eq!(
+        host.count_subisomorphisms_vf2(&pattern, &colors, None, None)
+            .unwrap(),
+        2
+    );
+
+    let triple = [3];
+    let colors = Vf2Colors::edges(&orders, &triple);
+    assert!(
+        host.subisomorphic_vf2(&pattern, &colors, None, None)
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn test_isomorphic_vf2_colors() {
+    let g1 = Graph::from_edges(&[(0, 1), (1, 2)], 3, false).unwrap();
+    let g2 = Graph::from_edges(&[(0, 1), (1, 2)], 3, false).unwrap();
+    let c1 = [0, 0, 1];
+    let c2 = [1, 0, 0];
+    let colors = Vf2Colors::vertices(&c1, &c2);
+    assert_eq!(
+        g1.count_isomorphisms_vf2(&g2, &colors, None, None).unwrap(),
+        1
+    );
+    let (map12, _map21) = g1
+        .isomorphic_vf2(&g2, &colors, None, None)
+        .unwrap()
+        .unwrap();
+    assert_eq!(map12, vec![2, 1, 0]);
+}
+
+#[test]
+fn test_vf2_node_compat_closure() {
+    // Pattern: a carbon bonded to an "aromatic or sp2" carbon.
+    let host = Graph::from_edges(&[(0, 1), (1, 2), (2, 3)], 4, false).unwrap();
+    let pattern = Graph::from_edges(&[(0, 1)], 2, false).unwrap();
+    let sp2 = [false, false, true, true];
+    let mut calls = 0;
+    let mut node_compat = |_: &Graph, _: &Graph, host_v: i64, pattern_v: i64| {
+        calls += 1;
+        pattern_v == 0 || sp2[host_v as usize]
+    };
+    let maps = host
+        .get_subisomorphisms_vf2(
+            &pattern,
+            &Vf2Colors::default(),
+            Some(&mut node_compat),
+            None,
+        )
+        .unwrap();
+    assert!(calls > 0);
+    let mut expected = vec![vec![1, 2], vec![2, 3], vec![3, 2]];
+    let mut maps = maps;
+    maps.sort();
+    expected.sort();
+    assert_eq!(maps, expected);
+}
+
+#[test]
+fn test_vf2_edge_compat_closure() {
+    let host = Graph::from_edges(&[(0, 1), (1, 2), (2, 3)], 4, false).unwrap();
+    let pattern = Graph::from_edges(&[(0, 1), (1, 2)], 3, false).unwrap();
+    // Bond orders in the host; the pattern accepts single or double bonds only.
+    let orders = [1, 2, 3];
+    let mut edge_compat = |g1: &Graph, g2: &Graph, host_e: i64, _pattern_e: i64| {
+        assert_eq!(g1.vcount(), 4);
+        assert_eq!(g2.vcount(), 3);
+        matches!(orders[host_e as usize], 1 | 2)
+    };
+    let count = host
+        .count_subisomorphisms_vf2(
+            &pattern,
+            &Vf2Colors::default(),
+            None,
+            Some(&mut edge_compat),
+        )
+        .unwrap();
+    // Only the path 0-1-2 (in both directions) avoids the triple bond.
+    assert_eq!(count, 2);
+}
+
+#[test]
+#[should_panic(expected = "compat failed")]
+fn test_vf2_compat_closure_panic() {
+    let g = Graph::ring(4, false, false, true).unwrap();
+    let mut node_compat =
+        |_: &Graph, _: &Graph, _: i64, _: i64| -> bool { panic!("compat failed") };
+    let _ = g.count_isomorphisms_vf2(&g, &Vf2Colors::default(), Some(&mut node_compat), None);
+}
+
+#[test]
+fn test_get_subisomorphisms_vf2_callback_streams_all() {
+    let host = Graph::ring(6, false, false, true).unwrap();
+    let pattern = Graph::from_edges(&[(0, 1), (1, 2)], 3, false).unwrap();
+    let mut seen = Vec::new();
+    host.get_subisomorphisms_vf2_callback(
+        &pattern,
+        &Vf2Colors::default(),
+        None,
+        None,
+        |map12, map21| {
+            assert_eq!(map12.len(), 6);
+            seen.push(map21.to_vec());
+            ControlFlow::Continue(())
+        },
+    )
+    .unwrap();
+    let mut expected = host
+        .get_subisomorphisms_vf2(&pattern, &Vf2Colors::default(), None, None)
+        .unwrap();
+    seen.sort();
+    expected.sort();
+    assert_eq!(seen, expected);
+}
+
+#[test]
+fn test_get_subisomorphisms_vf2_callback_early_stop() {
+    let host = Graph::full(8, false, false).unwrap();
+    let pattern = Graph::full(3, false, false).unwrap();
+    let mut first = Vec::new();
+    host.get_subisomorphisms_vf2_callback(
+        &pattern,
+        &Vf2Colors::default(),
+        None,
+        None,
+        |_map12, map21| {
+            first.push(map21.to_vec());
+            if first.len() == 5 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        },
+    )
+    .unwrap();
+    assert_eq!(first.len(), 5);
+}
+
+#[test]
+fn test_get_isomorphisms_vf2_callback() {
+    let ring = Graph::ring(5, false, false, true).unwrap();
+    let mut count = 0;
+    ring.get_isomorphisms_vf2_callback(&ring, &Vf2Colors::default(), None, None, |map12, map21| {
+        assert_eq!(map12.len(), 5);
+        assert_eq!(map21.len(), 5);
+        count += 1;
+        ControlFlow::Continue(())
+    })
+    .unwrap();
+    assert_eq!(count, 10);
+}
+
+#[test]
+fn test_subisomorphic_lad() {
+    let host = Graph::ring(6, false, false, true).unwrap();
+    let pattern = Graph::from_edges(&[(0, 1), (1, 2)], 3, false).unwrap();
+    let map = host
+        .subisomorphic_lad(&pattern, None, false, None)
+        .unwrap()
+        .unwrap();
+    assert_eq!(map.len(), 3);
+    assert!(host.are_adjacent(map[0], map[1]).unwrap());
+    assert!(host.are_adjacent(map[1], map[2]).unwrap());
+
+    let triangle = Graph::full(3, false, false).unwrap();
+    assert!(
+        host.subisomorphic_lad(&triangle, None, false, None)
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn test_subisomorphic_lad_time_limit() {
+    // Complete 10-partite graph on 60 vertices: dense, but without an
+    // 11-clique, so the search cannot stop at a first match.
+    let mut edges = Vec::new();
+    for u in 0..60 {
+        for v in (u + 1)..60 {
+            if u % 10 != v % 10 {
+                edges.push((u, v));
+            }
+        }
+    }
+    let host = Graph::from_edges(&edges, 60, false).unwrap();
+    let clique = Graph::full(11, false, false).unwrap();
+    assert_eq!(
+        host.subisomorphic_lad(&clique, None, false, Some(Duration::ZERO)),
+        Err(Error::Interrupted)
+    );
+    assert_eq!(
+        host.get_subisomorphisms_lad(&clique, None, false, Some(Duration::from_micros(50))),
+        Err(Error::Interrupted)
+    );
+
+    // Without a limit, the default handlers are back in place.
+    let triangle = Graph::full(3, false, false).unwrap();
+    assert!(
+        host.subisomorphic_lad(&triangle, None, false, None)
+            .unwrap()
+            .is_some()
+    );
+    let ring = Graph::ring(6, false, false, true).unwrap();
+    assert!(
+        ring.subisomorphic_lad(&triangle, None, false, None)
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn test_get_subisomorphisms_lad_induced() {
+    // A path on three vertices occurs in K4 only as a non-induced subgraph.
+    let host = Graph::full(4, false, false).unwrap();
+    let pattern = Graph::from_edges(&[(0, 1), (1, 2)], 3, false).unwrap();
+    let all = host
+        .get_subisomorphisms_lad(&pattern, None, false, None)
+        .unwrap();
+    assert_eq!(all.len(), 24);
+    let induced = host
+        .get_subisomorphisms_lad(&pattern, None, true, None)
+        .unwrap();
+    assert!(induced.is_empty());
+}
+
+#[test]
+fn test_get_subisomorphisms_lad_domains() {
+    let host = Graph::ring(6, false, false, true).unwrap();
+    let pattern = Graph::from_edges(&[(0, 1), (1, 2)], 3, false).unwrap();
+    // Pin the middle pattern vertex to host vertex 0.
+    let domains = vec![vec![0, 1, 2, 3, 4, 5], vec![0], vec![0, 1, 2, 3, 4, 5]];
+    let maps = host
+        .get_subisomorphisms_lad(
+            &pattern,
+            Some(&domains),
+            false,
+            Some(Duration::from_secs(10)),
+        )
+        .unwrap();
+    assert_eq!(maps.len(), 2);
+    for map in &maps {
+        assert_eq!(map[1], 0);
+    }
+}
+
+// === Isoclass and motif tests ===
+
+#[test]
+fn test_isoclass_roundtrip() {
+    let g = Graph::ring(4, false, false, true).unwrap();
+    let class = g.isoclass().unwrap();
+    let h = Graph::isoclass_create(4, class, false).unwrap();
+    assert!(g.isomorphic(&h).unwrap());
+
+    let full = Graph::full(5, false, false).unwrap();
+    let triangle = Graph::full(3, false, false).unwrap();
+    assert_eq!(
+        full.isoclass_subgraph(&[0, 2, 4]).unwrap(),
+        triangle.isoclass().unwrap()
+    );
+}
+
+#[test]
+fn test_graph_count() {
+    assert_eq!(Graph::graph_count(3, false).unwrap(), 4);
+    assert_eq!(Graph::graph_count(4, false).unwrap(), 11);
+    assert_eq!(Graph::graph_count(3, true).unwrap(), 16);
+}
+
+#[test]
+fn test_motifs_randesu() {
+    let g = Graph::full(4, false, false).unwrap();
+    let hist = g.motifs_randesu(3, None, None).unwrap();
+    assert_eq!(hist.len() as i64, Graph::graph_count(3, false).unwrap());
+    let triangle = Graph::full(3, false, false).unwrap().isoclass().unwrap();
+    assert_eq!(hist[triangle as usize], 4.0);
+    assert_eq!(g.motifs_randesu_no(3, None, None).unwrap(), 4.0);
+}
+
+#[test]
+fn test_motifs_randesu_seeded() {
+    let g = Graph::famous("Zachary").unwrap();
+    let cut_prob = [0.0, 0.5, 0.5];
+    let a = g.motifs_randesu(3, Some(&cut_prob), Some(42)).unwrap();
+    let b = g.motifs_randesu(3, Some(&cut_prob), Some(42)).unwrap();
+    assert_eq!(format!("{a:?}"), format!("{b:?}"));
+    let est = g
+        .motifs_randesu_estimate(3, None, 10, None, Some(7))
+        .unwrap();
+    assert!(est > 0.0);
+}
+
+#[test]
+fn test_motifs_randesu_callback() {
+    let g = Graph::full(5, false, false).unwrap();
+    let mut found = 0;
+    g.motifs_randesu_callback(3, None, None, |vids, _isoclass| {
+        assert_eq!(vids.len(), 3);
+        found += 1;
+        ControlFlow::Continue(())
+    })
+    .unwrap();
+    assert_eq!(found, 10);
+
+    let mut found = 0;
+    g.motifs_randesu_callback(3, None, None, |_vids, _isoclass| {
+        found += 1;
+        ControlFlow::Break(())
+    })
+    .unwrap();
+    assert_eq!(found, 1);
+}
+
+#[test]
+fn test_dyad_census() {
+    let g = Graph::from_edges(&[(0, 1), (1, 0), (1, 2)], 4, true).unwrap();
+    let census = g.dyad_census().unwrap();
+    assert_eq!(census.mutual, 1.0);
+    assert_eq!(census.asymmetric, 1.0);
+    assert_eq!(census.null, 4.0);
+}
+
+#[test]
+fn test_triad_census() {
+    let g = Graph::from_edges(&[(0, 1), (1, 2), (2, 0)], 3, true).unwrap();
+    let census = g.triad_census().unwrap();
+    assert_eq!(census.t030c, 1.0);
+    assert_eq!(census.t003, 0.0);
+}
+
+// === Clique tests ===
+
+fn sorted_sets(mut sets: Vec<Vec<i64>>) -> Vec<Vec<i64>> {
+    for s in &mut sets {
+        s.sort();
+    }
+    sets.sort();
+    sets
+}
+
+#[test]
+fn test_cliques() {
+    // Two triangles sharing the edge 1-2.
+    let g = Graph::from_edges(&[(0, 1), (0, 2), (1, 2), (1, 3), (2, 3)], 4, false).unwrap();
+    assert_eq!(
+        sorted_sets(g.cliques(3, 0).unwrap()),
+        vec![vec![0, 1, 2], vec![1, 2, 3]]
+    );
+    assert_eq!(g.cliques(2, 2).unwrap().len(), 5);
+    assert_eq!(g.clique_number().unwrap(), 3);
+    assert_eq!(g.clique_size_hist(0, 0).unwrap(), vec![4.0, 5.0, 2.0]);
+}
+
+#[test]
+fn test_maximal_cliques() {
+    let g = Graph::from_edges(&[(0, 1), (0, 2), (1, 2), (2, 3)], 4, false).unwrap();
+    assert_eq!(
+        sorted_sets(g.maximal_cliques(0, 0).unwrap()),
+        vec![vec![0, 1, 2], vec![2, 3]]
+    );
+    assert_eq!(g.maximal_cliques(3, 0).unwrap().len(), 1);
+    assert_eq!(g.maximal_cliques_count(0, 0).unwrap(), 2);
+    assert_eq!(g.maximal_cliques_hist(0, 0).unwrap(), vec![0.0, 1.0, 1.0]);
+    assert_eq!(
+        sorted_sets(g.largest_cliques().unwrap()),
+        vec![vec![0, 1, 2]]
+    );
+
+    // Subsets partitioning the vertices together yield every maximal clique once.
+    let mut parts = g.maximal_cliques_subset(&[0, 1], 0, 0).unwrap();
+    parts.extend(g.maximal_cliques_subset(&[2, 3], 0, 0).unwrap());
+    assert_eq!(sorted_sets(parts), vec![vec![0, 1, 2], vec![2, 3]]);
+}
+
+#[test]
+fn test_weighted_cliques() {
+    let g = Graph::from_edges(&[(0, 1), (0, 2), (1, 2), (2, 3)], 4, false).unwrap();
+    let weights = [1.0, 1.0, 1.0, 5.0];
+    assert_eq!(g.weighted_clique_number(&weights).unwrap(), 6.0);
+    assert_eq!(
+        sorted_sets(g.largest_weighted_cliques(&weights).unwrap()),
+        vec![vec![2, 3]]
+    );
+    let heavy = g.weighted_cliques(&weights, true, 4.0, 0.0).unwrap();
+    assert_eq!(sorted_sets(heavy), vec![vec![2, 3]]);
+}
+
+#[test]
+fn test_independent_vertex_sets() {
+    let g = Graph::ring(5, false, false, true).unwrap();
+    assert_eq!(g.independence_number().unwrap(), 2);
+    assert_eq!(g.largest_independent_vertex_sets().unwrap().len(), 5);
+    assert_eq!(g.independent_vertex_sets(2, 2).unwrap().len(), 5);
+    assert_eq!(g.maximal_independent_vertex_sets(0, 0).unwrap().len(), 5);
+}
+
+#[test]
+fn test_cliques_callback() {
+    let g = Graph::full(5, false, false).unwrap();
+    let mut triangles = 0;
+    g.cliques_callback(3, 3, |clique| {
+        assert_eq!(clique.len(), 3);
+        triangles += 1;
+        ControlFlow::Continue(())
+    })
+    .unwrap();
+    assert_eq!(triangles, 10);
+
+    let mut seen = 0;
+    g.cliques_callback(0, 0, |_clique| {
+        seen += 1;
+        if seen == 3 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .unwrap();
+    assert_eq!(seen, 3);
+}
+
+#[test]
+fn test_maximal_cliques_callback() {
+    let g = Graph::from_edges(&[(0, 1), (0, 2), (1, 2), (2, 3)], 4, false).unwrap();
+    let mut cliques = Vec::new();
+    g.maximal_cliques_callback(0, 0, |clique| {
+        cliques.push(clique.to_vec());
+        ControlFlow::Continue(())
+    })
+    .unwrap();
+    assert_eq!(sorted_sets(cliques), vec![vec![0, 1, 2], vec![2, 3]]);
+}
+
+#[test]
+fn test_maximal_cliques_file() {
+    let g = Graph::from_edges(&[(0, 1), (0, 2), (1, 2), (2, 3)], 4, false).unwrap();
+    let path = std::env::temp_dir().join(format!("igraph-cliques-{}.txt", std::process::id()));
+    g.maximal_cliques_file(&path, 0, 0).unwrap();
+    let contents = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let cliques: Vec<Vec<i64>> = contents
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|v| v.parse().unwrap())
+                .collect()
+        })
+        .collect();
+    assert_eq!(sorted_sets(cliques), vec![vec![0, 1, 2], vec![2, 3]]);
+}
+
+// === Cycle tests ===
+
+#[test]
+fn test_girth() {
+    let g = Graph::famous("Petersen").unwrap();
+    let (girth, circle) = g.girth().unwrap();
+    assert_eq!(girth, 5.0);
+    assert_eq!(circle.len(), 5);
+
+    let tree = Graph::kary_tree(7, 2, TreeMode::Undirected).unwrap();
+    let (girth, circle) = tree.girth().unwrap();
+    assert!(girth.is_infinite());
+    assert!(circle.is_empty());
+}
+
+#[test]
+fn test_find_cycle() {
+    let g = Graph::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 1)], 4, true).unwrap();
+    let cycle = g.find_cycle(NeighborMode::Out).unwrap().unwrap();
+    assert_eq!(cycle.edges.len(), 3);
+    assert_eq!(cycle.vertices.len(), 3);
+
+    let dag = Graph::from_edges(&[(0, 1), (1, 2), (0, 2)], 3, true).unwrap();
+    assert!(dag.find_cycle(NeighborMode::Out).unwrap().is_none());
+    assert!(dag.find_cycle(NeighborMode::All).unwrap().is_some());
+}
+
+#[test]
+fn test_cycle_bases() {
+    // Naphthalene skeleton: two fused six-membered rings.
+    let g = Graph::from_edges(
+        &[
+            (0, 1),
+            (1, 2),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 0),
+            (4, 6),
+            (6, 7),
+            (7, 8),
+            (8, 9),
+            (9, 5),
+        ],
+        10,
+        false,
+    )
+    .unwrap();
+    let fundamental = g.fundamental_cycles(None, None).unwrap();
+    assert_eq!(fundamental.len(), 2);
+    fundamental.iter().for_each(|c| assert_closed_walk(&g, c));
+
+    let basis = g.minimum_cycle_basis(None, true).unwrap();
+    assert_eq!(basis.len(), 2);
+    assert!(basis.iter().all(|ring| ring.edges.len() == 6));
+    basis.iter().for_each(|c| assert_closed_walk(&g, c));
+    let mut rings: Vec<_> = basis
+        .iter()
+        .map(|c| {
+            let mut v = c.vertices.clone();
+            v.sort();
+            v
+        })
+        .collect();
+    rings.sort();
+    assert_eq!(rings, vec![vec![0, 1, 2, 3, 4, 5], vec![4, 5, 6, 7, 8, 9]]);
+
+    assert_eq!(
+        g.fundamental_cycles(Some(10), None),
+        Err(Error::InvalidVertexId)
+    );
+}
+
+#[test]
+fn test_cycle_basis_multigraph() {
+    // A self-loop on 0 and a double edge 1-2.
+    let g = Graph::from_edges(&[(0, 0), (1, 2), (2, 1), (0, 1)], 3, false).unwrap();
+    let mut basis = g.minimum_cycle_basis(None, true).unwrap();
+    basis.sort_by_key(|c| c.edges.len());
+    assert_eq!(basis[0].vertices, vec![0]);
+    assert_eq!(basis[1].edges.len(), 2);
+    basis.iter().for_each(|c| assert_closed_walk(&g, c));
+}
+
+/// Check that consecutive cycle vertices are joined by the listed edges,
+/// with the last edge leading back to the first vertex.
+fn assert_closed_walk(g: &Graph, cycle: &Cycle) {
+    let n = cycle.vertices.len();
+    assert_eq!(n, cycle.edges.len());
+    for (i, &eid) in cycle.edges.iter().enumerate() {
+        let (from, to) = g.edge(eid).unwrap();
+        let (a, b) = (cycle.vertices[i], cycle.vertices[(i + 1) % n]);
+        assert!((from, to) == (a, b) || (from, to) == (b, a));
+    }
+}
+
+#[test]
+fn test_simple_cycles() {
+    // Two triangles sharing the edge 1-2 also form a 4-cycle.
+    let g = Graph::from_edges(&[(0, 1), (0, 2), (1, 2), (1, 3), (2, 3)], 4, false).unwrap();
+    let cycles = g.simple_cycles(NeighborMode::All, None, None).unwrap();
+    assert_eq!(cycles.len(), 3);
+    for c in &cycles {
+        assert_eq!(c.vertices.len(), c.edges.len());
+    }
+    let triangles = g.simple_cycles(NeighborMode::All, None, Some(3)).unwrap();
+    assert_eq!(triangles.len(), 2);
+    let squares = g.simple_cycles(NeighborMode::All, Some(4), None).unwrap();
+    assert_eq!(squares.len(), 1);
+}
+
+#[test]
+fn test_simple_cycles_callback() {
+    let g = Graph::full(5, false, false).unwrap();
+    let mut count = 0;
+    g.simple_cycles_callback(NeighborMode::All, Some(3), Some(3), |vertices, edges| {
+        assert_eq!(vertices.len(), 3);
+        assert_eq!(edges.len(), 3);
+        count += 1;
+        ControlFlow::Continue(())
+    })
+    .unwrap();
+    assert_eq!(count, 10);
+
+    let mut first = None;
+    g.simple_cycles_callback(NeighborMode::All, None, None, |vertices, _edges| {
+        first = Some(vertices.to_vec());
+        ControlFlow::Break(())
+    })
+    .unwrap();
+    assert!(first.is_some());
+}
+
+#[test]
+fn test_acyclicity_predicates() {
+    let tree = Graph::kary_tree(7, 2, TreeMode::Out).unwrap();
+    assert!(tree.is_acyclic().unwrap());
+    assert!(tree.is_dag().unwrap());
+    assert!(tree.is_tree(NeighborMode::Out).unwrap());
+    assert!(!tree.is_tree(NeighborMode::In).unwrap());
+    assert!(tree.is_forest(NeighborMode::All).unwrap());
+
+    let forest = Graph::from_edges(&[(0, 1), (2, 3)], 4, false).unwrap();
+    assert!(forest.is_forest(NeighborMode::All).unwrap());
+    assert!(!forest.is_tree(NeighborMode::All).unwrap());
+    assert!(!forest.is_dag().unwrap());
+
+    let ring = Graph::ring(4, false, false, true).unwrap();
+    assert!(!ring.is_acyclic().unwrap());
+    assert!(!ring.is_forest(NeighborMode::All).unwrap());
+}
+
+// === DAG tests ===
+
+fn assert_topological(g: &Graph, order: &[i64]) {
+    let pos: std::collections::HashMap<i64, usize> =
+        order.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+    for (from, to) in g.get_edgelist().unwrap() {
+        if let (Some(a), Some(b)) = (pos.get(&from), pos.get(&to)) {
+            assert!(a < b, "edge {from}->{to} violates the order");
+        }
+    }
+}
+
+#[test]
+fn test_topological_sorting() {
+    let g = Graph::from_edges(&[(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)], 5, true).unwrap();
+    let order = g.topological_sorting(NeighborMode::Out, false).unwrap();
+    assert_eq!(order.len(), 5);
+    assert_eq!(order[0], 0);
+    assert_topological(&g, &order);
+
+    let reversed = g.topological_sorting(NeighborMode::In, false).unwrap();
+    assert_eq!(reversed[0], 4);
+}
+
+#[test]
+fn test_topological_sorting_cycle() {
+    // 0 -> 1 -> 2 -> 1 is a cycle, 3 -> 0 is upstream of it.
+    let g = Graph::from_edges(&[(3, 0), (0, 1), (1, 2), (2, 1)], 4, true).unwrap();
+    assert_eq!(
+        g.topological_sorting(NeighborMode::Out, false),
+        Err(Error::InvalidValue)
+    );
+    let partial = g.topological_sorting(NeighborMode::Out, true).unwrap();
+    assert_eq!(partial, vec![3, 0]);
+}
+
+#[test]
+fn test_feedback_arc_set() {
+    let g = Graph::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)], 4, true).unwrap();
+    for algo in [FasAlgorithm::ExactIp, FasAlgorithm::ApproxEades] {
+        let fas = g.feedback_arc_set(None, algo).unwrap();
+        assert_eq!(fas.len(), 1);
+        let mut h = Graph::from_edges(&g.get_edgelist().unwrap(), 4, true).unwrap();
+        h.delete_edges(&fas).unwrap();
+        assert!(h.is_dag().unwrap());
+    }
+
+    // A heavy weight steers the exact solution away from edge 0.
+    let fas = g
+        .feedback_arc_set(Some(&[10.0, 1.0, 10.0, 1.0]), FasAlgorithm::ExactIp)
+        .unwrap();
+    assert_eq!(fas, vec![1]);
+}
+
+#[test]
+fn test_feedback_vertex_set() {
+    // Two triangles sharing vertex 0.
+    let g = Graph::from_edges(&[(0, 1), (1, 2), (2, 0), (0, 3), (3, 4), (4, 0)], 5, false).unwrap();
+    assert_eq!(g.feedback_vertex_set(None).unwrap(), vec![0]);
+}
+
+// === Eulerian tests ===
+
+/// Check that `vertices` is a walk along `edges` in `g`.
+fn assert_walk(g: &Graph, edges: &[i64], vertices: &[i64]) {
+    assert_eq!(vertices.len(), edges.len() + 1);
+    for (i, &eid) in edges.iter().enumerate() {
+        let (from, to) = g.edge(eid).unwrap();
+        let (a, b) = (vertices[i], vertices[i + 1]);
+        if g.is_directed() {
+            assert_eq!((from, to), (a, b));
+        } else {
+            assert!((from, to) == (a, b) || (from, to) == (b, a));
+        }
+    }
+    let mut used = edges.to_vec();
+    used.sort();
+    assert_eq!(used, (0..g.ecount()).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_eulerian_undirected_multigraph() {
+    // Double edge 0-1 and a self-loop on 2: vertices 1 and 2 have odd degree.
+    let g = Graph::from_edges(&[(0, 1), (0, 1), (1, 2), (2, 2)], 3, false).unwrap();
+    assert_eq!(g.is_eulerian().unwrap(), (true, false));
+    let (edges, vertices) = g.eulerian_path().unwrap().unwrap();
+    assert_walk(&g, &edges, &vertices);
+    assert!(g.eulerian_cycle().unwrap().is_none());
+}
+
+#[test]
+fn test_eulerian_cycle_with_loop() {
+    let g = Graph::from_edges(&[(0, 1), (1, 0), (1, 1)], 2, false).unwrap();
+    assert_eq!(g.is_eulerian().unwrap(), (true, true));
+    let (edges, vertices) = g.eulerian_cycle().unwrap().unwrap();
+    assert_walk(&g, &edges, &vertices);
+    assert_eq!(vertices.first(), vertices.last());
+}
+
+#[test]
+fn test_eulerian_directed() {
+    let g = Graph::from_edges(&[(0, 1), (1, 2), (2, 0), (1, 1), (2, 0), (0, 2)], 3, true).unwrap();
+    assert_eq!(g.is_eulerian().unwrap(), (true, true));
+    let (edges, vertices) = g.eulerian_cycle().unwrap().unwrap();
+    assert_walk(&g, &edges, &vertices);
+
+    let path_only = Graph::from_edges(&[(0, 1), (1, 2), (1, 1)], 3, true).unwrap();
+    assert_eq!(path_only.is_eulerian().unwrap(), (true, false));
+    let (edges, vertices) = path_only.eulerian_path().unwrap().unwrap();
+    assert_walk(&path_only, &edges, &vertices);
+    assert_eq!(vertices, vec![0, 1, 1, 2]);
+
+    let none = Graph::from_edges(&[(0, 1), (0, 2)], 3, true).unwrap();
+    assert_eq!(none.is_eulerian().unwrap(), (false, false));
+    assert!(none.eulerian_path().unwrap().is_none());
+}
+
+// === Spanning tree tests ===
+
+#[test]
+fn test_minimum_spanning_tree_weighted() {
+    let g = Graph::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)], 4, false).unwrap();
+    let weights = [1.0, 2.0, 1.0, 5.0, 3.0];
+    for algo in [
+        MstAlgorithm::Prim,
+        MstAlgorithm::Kruskal,
+        MstAlgorithm::Automatic,
+    ] {
+        let mut eids = g.minimum_spanning_tree(Some(&weights), algo).unwrap();
+        eids.sort();
+        assert_eq!(eids, vec![0, 1, 2]);
+    }
+    let tree = g
+        .minimum_spanning_tree_graph(Some(&weights), MstAlgorithm::Kruskal)
+        .unwrap();
+    assert_eq!(tree.vcount(), 4);
+    assert_eq!(tree.ecount(), 3);
+    assert!(tree.is_tree(NeighborMode::All).unwrap());
+}
+
+#[test]
+fn test_minimum_spanning_tree_unweighted() {
+    let g = Graph::ring(5, false, false, true).unwrap();
+    let eids = g
+        .minimum_spanning_tree(None, MstAlgorithm::Unweighted)
+        .unwrap();
+    assert_eq!(eids.len(), 4);
+    assert_eq!(
+        g.minimum_spanning_tree(None, MstAlgorithm::Prim),
+        Err(Error::InvalidValue)
+    );
+}
+
+#[test]
+fn test_random_spanning_tree() {
+    let g = Graph::full(6, false, false).unwrap();
+    let a = g.random_spanning_tree(None, Some(7)).unwrap();
+    let b = g.random_spanning_tree(None, Some(7)).unwrap();
+    assert_eq!(a, b);
+    assert_eq!(a.len(), 5);
+
+    // Only the component of the given vertex is spanned.
+    let g = Graph::from_edges(&[(0, 1), (1, 2), (2, 0), (3, 4)], 5, false).unwrap();
+    let eids = g.random_spanning_tree(Some(0), Some(1)).unwrap();
+    assert_eq!(eids.len(), 2);
+    assert!(eids.iter().all(|&e| e < 3));
+}
+
+#[test]
+fn test_spanner_stretch() {
+    let g = Graph::full(8, false, false).unwrap();
+    let eids = g.spanner(3.0, None).unwrap();
+    let edges: Vec<_> = eids.iter().map(|&e| g.edge(e).unwrap()).collect();
+    let sp = Graph::from_edges(&edges, 8, false).unwrap();
+    let dist = sp
+        .distances(VertexSelector::All, VertexSelector::All, NeighborMode::All)
+        .unwrap();
+    assert!(dist.iter().all(|&d| d <= 3.0));
+    assert_eq!(g.spanner(0.5, None), Err(Error::InvalidValue));
+}
+
+#[test]
+fn test_unfold_tree() {
+    let g = Graph::ring(3, false, false, true).unwrap();
+    let (tree, index) = g.unfold_tree(NeighborMode::All, &[0]).unwrap();
+    assert_eq!(tree.vcount(), 4);
+    assert_eq!(tree.ecount(), 3);
+    assert!(tree.is_tree(NeighborMode::All).unwrap());
+    assert_eq!(&index[..3], &[0, 1, 2]);
+    assert!(index[3] == 1 || index[3] == 2);
+}
+
+// === Traversal tests ===
+
+/// A small tree 0-{1,2}, 1-3, 2-4 plus an isolated vertex 5.
+fn traversal_graph() -> Graph {
+    Graph::from_edges(&[(0, 1), (0, 2), (1, 3), (2, 4)], 6, false).unwrap()
+}
+
+#[test]
+fn test_bfs() {
+    let g = traversal_graph();
+    let res = g.bfs(&[0], NeighborMode::All, false, None).unwrap();
+    assert_eq!(res.order, vec![0, 1, 2, 3, 4]);
+    assert_eq!(res.rank[4], 4);
+    assert_eq!(res.parents[0], -1);
+    assert_eq!(res.parents[3], 1);
+    assert_eq!(res.dist[..5], [0, 1, 1, 2, 2]);
+    assert!(res.dist[5] < 0);
+
+    let res = g.bfs(&[0], NeighborMode::All, true, None).unwrap();
+    assert_eq!(res.order, vec![0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_bfs_restricted_and_multi_root() {
+    let g = traversal_graph();
+    let res = g
+        .bfs(&[0], NeighborMode::All, false, Some(&[0, 1, 3]))
+        .unwrap();
+    assert_eq!(res.order, vec![0, 1, 3]);
+
+    let g = Graph::from_edges(&[(0, 1), (2, 3)], 4, false).unwrap();
+    let res = g.bfs(&[0, 2], NeighborMode::All, false, None).unwrap();
+    assert_eq!(res.order, vec![0, 1, 2, 3]);
+    assert_eq!(res.parents[2], -1);
+    assert_eq!(res.dist[3], 1);
+}
+
+#[test]
+fn test_bfs_callback_stops() {
+    let g = traversal_graph();
+    let mut visits = Vec::new();
+    g.bfs_callback(&[0], NeighborMode::All, true, None, |v| {
+        visits.push(v);
+        if visits.len() == 3 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .unwrap();
+    let vids: Vec<_> = visits.iter().map(|v| v.vid).collect();
+    assert_eq!(vids, vec![0, 1, 2]);
+    assert_eq!(visits[2].dist, 1);
+    assert_eq!(visits[2].rank, 2);
+}
+
+#[test]
+fn test_bfs_simple() {
+    let g = traversal_graph();
+    let (order, layers, parents) = g.bfs_simple(0, NeighborMode::All).unwrap();
+    assert_eq!(order, vec![0, 1, 2, 3, 4]);
+    assert_eq!(layers, vec![0, 1, 3, 5]);
+    assert_eq!(parents[3], 1);
+    assert_eq!(parents[4], 2);
+}
+
+#[test]
+fn test_dfs() {
+    let g = traversal_graph();
+    let res = g.dfs(0, NeighborMode::All, false).unwrap();
+    assert_eq!(res.order, vec![0, 1, 3, 2, 4]);
+    assert_eq!(res.order_out, vec![3, 1, 4, 2, 0]);
+    assert_eq!(res.parents[4], 2);
+    assert_eq!(res.dist[3], 2);
+
+    let res = g.dfs(0, NeighborMode::All, true).unwrap();
+    assert_eq!(res.order.len(), 6);
+}
+
+#[test]
+fn test_dfs_callback_events() {
+    let g = traversal_graph();
+    let mut events = Vec::new();
+    g.dfs_callback(0, NeighborMode::All, false, |e| {
+        events.push(e);
+        match e {
+            DfsEvent::Finish { vid: 1, .. } => ControlFlow::Break(()),
+            _ => ControlFlow::Continue(()),
+        }
+    })
+    .unwrap();
+    assert_eq!(
+        events,
+        vec![
+            DfsEvent::Discover { vid: 0, dist: 0 },
+            DfsEvent::Discover { vid: 1, dist: 1 },
+            DfsEvent::Discover { vid: 3, dist: 2 },
+            DfsEvent::Finish { vid: 3, dist: 2 },
+            DfsEvent::Finish { vid: 1, dist: 1 },
+        ]
+    );
+}
+
+#[test]
+fn test_bfs_iter() {
+    let g = traversal_graph();
+    let items: Vec<_> = g.bfs_iter(0, NeighborMode::All).unwrap().collect();
+    assert_eq!(
+        items,
+        vec![
+            (0, 0, None),
+            (1, 1, Some(0)),
+            (2, 1, Some(0)),
+            (3, 2, Some(1)),
+            (4, 2, Some(2)),
+        ]
+    );
+    let order: Vec<_> = items.iter().map(|&(v, _, _)| v).collect();
+    assert_eq!(
+        order,
+        g.bfs(&[0], NeighborMode::All, false, None).unwrap().order
+    );
+
+    let shallow: Vec<_> = g
+        .bfs_iter(0, NeighborMode::All)
+        .unwrap()
+        .take_while(|&(_, depth, _)| depth <= 1)
+        .map(|(v, _, _)| v)
+        .collect();
+    assert_eq!(shallow, vec![0, 1, 2]);
+    assert!(g.bfs_iter(6, NeighborMode::All).is_err());
+}
+
+#[test]
+fn test_dfs_iter() {
+    let g = traversal_graph();
+    let items: Vec<_> = g.dfs_iter(0, NeighborMode::All).unwrap().collect();
+    assert_eq!(
+        items,
+        vec![
+            (0, 0, None),
+            (1, 1, Some(0)),
+            (3, 2, Some(1)),
+            (2, 1, Some(0)),
+            (4, 2, Some(2)),
+        ]
+    );
+    let order: Vec<_> = items.iter().map(|&(v, _, _)| v).collect();
+    assert_eq!(order, g.dfs(0, NeighborMode::All, false).unwrap().order);
+
+    // Direction is respected and cycles are visited once.
+    let g = Graph::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 2), (3, 0)], 4, true).unwrap();
+    let out: Vec<_> = g
+        .dfs_iter(0, NeighborMode::Out)
+        .unwrap()
+        .map(|(v, _, _)| v)
+        .collect();
+    assert_eq!(out, vec![0, 1, 2]);
+    let into: Vec<_> = g
+        .bfs_iter(0, NeighborMode::In)
+        .unwrap()
+        .map(|(v, _, _)| v)
+        .collect();
+    assert_eq!(into, vec![0, 2, 3, 1]);
+}
+
+// === Core decomposition tests ===
+
+/// A 4-clique {0, 1, 2, 3} with a triangle 3-4-5 attached and a pendant vertex 6.
+fn core_graph() -> Graph {
+    Graph::from_edges(
+        &[
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (1, 2),
+            (1, 3),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 3),
+            (5, 6),
+        ],
+        7,
+        false,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_coreness() {
+    let g = core_graph();
+    assert_eq!(
+        g.coreness(NeighborMode::All).unwrap(),
+        vec![3, 3, 3, 3, 2, 2, 1]
+    );
+    let (core, vids) = g.k_core(3, NeighborMode::All).unwrap();
+    assert_eq!(vids, vec![0, 1, 2, 3]);
+    assert_eq!(core.vcount(), 4);
+    assert_eq!(core.ecount(), 6);
+}
+
+#[test]
+fn test_trussness() {
+    let g = core_graph();
+    assert_eq!(g.trussness().unwrap(), vec![4, 4, 4, 4, 4, 4, 3, 3, 3, 2]);
+    let (truss, vids) = g.k_truss(3).unwrap();
+    assert_eq!(vids, vec![0, 1, 2, 3, 4, 5]);
+    assert_eq!(truss.ecount(), 9);
+    let (truss, vids) = g.k_truss(4).unwrap();
+    assert_eq!(vids, vec![0, 1, 2, 3]);
+    assert_eq!(truss.ecount(), 6);
+
+    let multi = Graph::from_edges(&[(0, 1), (0, 1)], 2, false).unwrap();
+    assert_eq!(multi.trussness(), Err(Error::Unimplemented));
+}
+
+// === Transitivity tests ===
+
+#[test]
+fn test_transitivity_global() {
+    let full = Graph::full(4, false, false).unwrap();
+    assert!((full.transitivity(TransitivityMode::Nan).unwrap() - 1.0).abs() < 1e-10);
+    let ring = Graph::ring(5, false, false, true).unwrap();
+    assert_eq!(ring.transitivity(TransitivityMode::Nan).unwrap(), 0.0);
+    let empty = Graph::empty(3, false).unwrap();
+    assert!(empty.transitivity(TransitivityMode::Nan).unwrap().is_nan());
+    assert_eq!(empty.transitivity(TransitivityMode::Zero).unwrap(), 0.0);
+}
+
+#[test]
+fn test_transitivity_local() {
+    // Triangle 0-1-2 with a pendant vertex 3 on 0.
+    let g = Graph::from_edges(&[(0, 1), (1, 2), (2, 0), (0, 3)], 4, false).unwrap();
+    let local = g
+        .transitivity_local(VertexSelector::All, TransitivityMode::Zero)
+        .unwrap();
+    assert!((local[0] - 1.0 / 3.0).abs() < 1e-10);
+    assert_eq!(local[1..], [1.0, 1.0, 0.0]);
+    let local = g
+        .transitivity_local(VertexSelector::List(&[3, 1]), TransitivityMode::Nan)
+        .unwrap();
+    assert!(local[0].is_nan());
+    assert_eq!(local[1], 1.0);
+
+    let avg = g.transitivity_avglocal(TransitivityMode::Zero).unwrap();
+    assert!((avg - (1.0 / 3.0 + 2.0) / 4.0).abs() < 1e-10);
+    let avg = g.transitivity_avglocal(TransitivityMode::Nan).unwrap();
+    assert!((avg - (1.0 / 3.0 + 2.0) / 3.0).abs() < 1e-10);
+
+    let barrat = g
+        .transitivity_barrat(VertexSelector::All, None, TransitivityMode::Zero)
+        .unwrap();
+    let weighted = g
+        .transitivity_barrat(VertexSelector::All, Some(&[1.0; 4]), TransitivityMode::Zero)
+        .unwrap();
+    let local = g
+        .transitivity_local(VertexSelector::All, TransitivityMode::Zero)
+        .unwrap();
+    for ((b, w), l) in barrat.iter().zip(&weighted).zip(&local) {
+        assert!((b - l).abs() < 1e-10);
+        assert!((w - l).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn test_count_triangles() {
+    let g = Graph::full(4, false, false).unwrap();
+    assert_eq!(g.count_triangles().unwrap(), 4.0);
+    assert_eq!(
+        g.count_adjacent_triangles(VertexSelector::All).unwrap(),
+        vec![3.0; 4]
+    );
+    assert_eq!(
+        g.count_adjacent_triangles(VertexSelector::Single(2))
+            .unwrap(),
+        vec![3.0]
+    );
+}
+
+#[test]
+fn test_ecc() {
+    let g = Graph::full(4, false, false).unwrap();
+    assert_eq!(
+        g.ecc(EdgeSelector::All, 3, false, false).unwrap(),
+        vec![2.0; 6]
+    );
+    assert_eq!(
+        g.ecc(EdgeSelector::List(&[0, 5]), 3, false, true).unwrap(),
+        vec![1.0; 2]
+    );
+}
+
+// === Neighborhood tests ===
+
+#[test]
+fn test_neighborhood() {
+    let g = Graph::ring(5, false, false, false).unwrap();
+    let nb = g
+        .neighborhood(VertexSelector::Single(2), 1, NeighborMode::All, 0)
+        .unwrap();
+    assert_eq!(nb.len(), 1);
+    assert_eq!(nb[0][0], 2);
+    assert_eq!(sorted_sets(nb), vec![vec![1, 2, 3]]);
+    let nb = g
+        .neighborhood(VertexSelector::Single(2), 2, NeighborMode::All, 2)
+        .unwrap();
+    assert_eq!(sorted_sets(nb), vec![vec![0, 4]]);
+    assert_eq!(
+        g.neighborhood_size(VertexSelector::All, 1, NeighborMode::All, 0)
+            .unwrap(),
+        vec![2, 3, 3, 3, 2]
+    );
+}
+
+#[test]
+fn test_neighborhood_graphs() {
+    let g = Graph::ring(5, false, false, false).unwrap();
+    let egos = g
+        .neighborhood_graphs(VertexSelector::List(&[0, 2]), 1, NeighborMode::All, 0)
+        .unwrap();
+    assert_eq!(egos.len(), 2);
+    assert_eq!((egos[0].0.vcount(), egos[0].0.ecount()), (2, 1));
+    assert_eq!(egos[0].1, vec![0, 1]);
+    let (ego, map) = &egos[1];
+    assert_eq!((ego.vcount(), ego.ecount()), (3, 2));
+    assert_eq!(map, &vec![1, 2, 3]);
+    for (from, to) in ego.get_edgelist().unwrap() {
+        assert!(
+            g.are_adjacent(map[from as usize], map[to as usize])
+                .unwrap()
+        );
+    }
+    // The center, original vertex 2, is the middle vertex of the path 1-2-3.
+    assert_eq!(
+        ego.degree(VertexSelector::Single(1), NeighborMode::All, Loops::Twice)
+            .unwrap(),
+        vec![2]
+    );
+}
+
+#[test]
+fn test_connect_neighborhood_and_graph_power() {
+    let mut g = Graph::ring(5, false, false, false).unwrap();
+    let squared = g.graph_power(2, false).unwrap();
+    assert_eq!(squared.ecount(), 7);
+    g.connect_neighborhood(2, NeighborMode::All).unwrap();
+    assert_eq!(g.ecount(), 7);
+    assert!(g.are_adjacent(0, 2).unwrap());
+    assert!(!g.are_adjacent(0, 3).unwrap());
+}
+
+#[test]
+fn test_local_scan() {
+    let g = Graph::ring(5, false, false, false).unwrap();
+    let full = Graph::full(5, false, false).unwrap();
+    assert_eq!(
+        g.local_scan_0(None, NeighborMode::All).unwrap(),
+        vec![1.0, 2.0, 2.0, 2.0, 1.0]
+    );
+    assert_eq!(
+        g.local_scan_0_them(&full, None, NeighborMode::All).unwrap(),
+        vec![1.0, 2.0, 2.0, 2.0, 1.0]
+    );
+    assert_eq!(
+        g.local_scan_1_ecount(None, NeighborMode::All).unwrap(),
+        vec![1.0, 2.0, 2.0, 2.0, 1.0]
+    );
+    assert_eq!(
+        g.local_scan_1_ecount_them(&full, None, NeighborMode::All)
+            .unwrap(),
+        vec![1.0, 3.0, 3.0, 3.0, 1.0]
+    );
+    let scan2 = g.local_scan_k_ecount(2, None, NeighborMode::All).unwrap();
+    assert_eq!(scan2, vec![2.0, 3.0, 4.0, 3.0, 2.0]);
+    assert_eq!(
+        g.local_scan_k_ecount_them(&full, 2, None, NeighborMode::All)
+            .unwrap(),
+        vec![3.0, 6.0, 10.0, 6.0, 3.0]
+    );
+
+    let weights = [2.0; 4];
+    let doubled = g
+        .local_scan_1_ecount(Some(&weights), NeighborMode::All)
+        .unwrap();
+    assert_eq!(doubled, vec![2.0, 4.0, 4.0, 4.0, 2.0]);
+
+    let nbs = g
+        .neighborhood(VertexSelector::All, 2, NeighborMode::All, 1)
+        .unwrap();
+    assert_eq!(g.local_scan_neighborhood_ecount(None, &nbs).unwrap(), scan2);
+    assert_eq!(
+        g.local_scan_subset_ecount(None, &[vec![0, 1, 2], vec![3, 4], vec![0, 4]])
+            .unwrap(),
+        vec![2.0, 1.0, 0.0]
+    );
+}
+
+// === Selector tests ===
+
+#[test]
+fn test_vertex_selectors() {
+    // Star with center 0 and leaves 1..=4, plus an edge 1-2.
+    let g = Graph::from_edges(&[(0, 1), (0, 2), (0, 3), (0, 4), (1, 2)], 5, false).unwrap();
+    let deg = |vids| g.degree(vids, NeighborMode::All, Loops::Twice).unwrap();
+    assert_eq!(deg(VertexSelector::All), vec![4, 2, 2, 1, 1]);
+    assert_eq!(deg(VertexSelector::Single(1)), vec![2]);
+    assert_eq!(deg(VertexSelector::List(&[4, 0])), vec![1, 4]);
+    assert_eq!(deg(VertexSelector::Range(1, 3)), vec![2, 2]);
+    assert_eq!(
+        deg(VertexSelector::Adjacent(1, NeighborMode::All)),
+        vec![4, 2]
+    );
+    let non_adjacent = deg(VertexSelector::NonAdjacent(1, NeighborMode::All));
+    assert_eq!(non_adjacent.iter().filter(|&&d| d == 1).count(), 2);
+    assert!(!non_adjacent.contains(&4));
+    assert!(deg(VertexSelector::List(&[])).is_empty());
+}
+
+#[test]
+fn test_selectors_in_paths_and_centrality() {
+    let g = Graph::ring(5, false, false, false).unwrap();
+    let dist = g
+        .distances(
+            VertexSelector::Single(0),
+            VertexSelector::List(&[4, 2]),
+            NeighborMode::All,
+        )
+        .unwrap();
+    assert_eq!(dist.to_vec_of_vecs(), vec![vec![4.0, 2.0]]);
+
+    let all = g.betweenness(VertexSelector::All, false).unwrap();
+    let some = g.betweenness(VertexSelector::Range(1, 3), false).unwrap();
+    assert_eq!(some, all[1..3]);
+    let all = g.closeness(VertexSelector::All, NeighborMode::All).unwrap();
+    let one = g
+        .closeness(VertexSelector::Single(2), NeighborMode::All)
+        .unwrap();
+    assert_eq!(one, vec![all[2]]);
+}
+
+#[test]
+fn test_edge_selectors() {
+    let g = Graph::from_edges(&[(0, 1), (1, 2), (2, 3), (1, 2), (3, 3)], 4, true).unwrap();
+    let ids = |es: EdgeSelector| -> Vec<(i64, i64)> { g.edge_endpoints(es).unwrap() };
+    assert_eq!(ids(EdgeSelector::All).len(), 5);
+    assert_eq!(ids(EdgeSelector::Single(2)), vec![(2, 3)]);
+    assert_eq!(ids(EdgeSelector::Range(0, 2)), vec![(0, 1), (1, 2)]);
+    let mut incident = ids(EdgeSelector::Incident(3, NeighborMode::All));
+    incident.sort();
+    assert_eq!(incident, vec![(2, 3), (3, 3)]);
+    assert_eq!(
+        ids(EdgeSelector::Pairs(&[(2, 3), (1, 0)], false)),
+        vec![(2, 3), (0, 1)]
+    );
+    assert_eq!(
+        ids(EdgeSelector::Path(&[0, 1, 2, 3], true)),
+        vec![(0, 1), (1, 2), (2, 3)]
+    );
+    assert_eq!(
+        ids(EdgeSelector::AllBetween(2, 1, false)),
+        vec![(1, 2), (1, 2)]
+    );
+    assert_eq!(
+        g.is_loop(EdgeSelector::Incident(3, NeighborMode::Out))
+            .unwrap(),
+        vec![true]
+    );
+}
+
+// === Vertex and edge iterator tests ===
+
+#[test]
+fn test_vertex_iter() {
+    let g = Graph::ring(5, false, false, true).unwrap();
+    let mut it = g.vertices().unwrap();
+    assert_eq!(it.len(), 5);
+    it.next();
+    assert_eq!(it.len(), 4);
+    assert_eq!(
+        g.vertices().unwrap().collect::<Vec<_>>(),
+        vec![0, 1, 2, 3, 4]
+    );
+    let picked: Vec<_> = g
+        .vertices_in(VertexSelector::List(&[3, 1]))
+        .unwrap()
+        .collect();
+    assert_eq!(picked, vec![3, 1]);
+    let mut adjacent: Vec<_> = g
+        .vertices_in(VertexSelector::Adjacent(0, NeighborMode::All))
+        .unwrap()
+        .collect();
+    adjacent.sort();
+    assert_eq!(adjacent, vec![1, 4]);
+    assert_eq!(g.vertices_in(VertexSelector::Range(2, 4)).unwrap().len(), 2);
+}
+
+#[test]
+fn test_edge_iter() {
+    for directed in [true, false] {
+        let g = Graph::from_edges(&[(0, 1), (2, 1), (3, 0), (2, 2)], 4, directed).unwrap();
+        let edges = g.edges().unwrap();
+        assert_eq!(edges.len(), 4);
+        for (eid, from, to) in edges {
+            assert_eq!(g.edge(eid).unwrap(), (from, to));
+        }
+    }
+
+    let g = Graph::from_edges(&[(0, 1), (1, 2), (2, 0)], 3, true).unwrap();
+    let picked: Vec<_> = g.edges_in(EdgeSelector::List(&[2, 0])).unwrap().collect();
+    assert_eq!(picked, vec![(2, 2, 0), (0, 0, 1)]);
+    let out: Vec<_> = g
+        .edges_in(EdgeSelector::Incident(1, NeighborMode::Out))
+        .unwrap()
+        .collect();
+    assert_eq!(out, vec![(1, 1, 2)]);
+    let sources: Vec<_> = g.edges().unwrap().map(|(_, from, _)| from).collect();
+    assert_eq!(sources, vec![0, 1, 2]);
+}
+
+// === Adjacency list tests ===
+
+#[test]
+fn test_adjlist_matches_neighbors() {
+    let g = Graph::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)], 4, true).unwrap();
+    let mut al = AdjList::new(&g, NeighborMode::All, Loops::Twice, true).unwrap();
+    al.sort();
+    assert_eq!(al.len(), 4);
+    for v in 0..4 {
+        let mut expected = g.neighbors(v, NeighborMode::All).unwrap();
+        expected.sort();
+        assert_eq!(&al[v], expected.as_slice());
+    }
+    assert_eq!(al.get(4), None);
+    assert_eq!(al.iter().map(<[i64]>::len).sum::<usize>(), 8);
+}
+
+#[test]
+fn test_adjlist_simplify_and_complementer() {
+    let g = Graph::from_edges(&[(0, 1), (1, 0), (1, 1), (1, 2)], 4, false).unwrap();
+    let mut al = AdjList::new(&g, NeighborMode::All, Loops::Twice, true).unwrap();
+    assert_eq!(al[1].len(), 5);
+    al.simplify().unwrap();
+    assert_eq!(&al[0], &[1]);
+    assert_eq!(&al[1], &[0, 2]);
+
+    let ring = Graph::ring(4, false, false, true).unwrap();
+    let mut comp = AdjList::complementer(&ring, NeighborMode::All, Loops::No).unwrap();
+    comp.sort();
+    assert_eq!(&comp[0], &[2]);
+    assert_eq!(&comp[1], &[3]);
+}
+
+#[test]
+fn test_adjlist_to_graph() {
+    let g = Graph::from_edges(&[(0, 1), (1, 2), (2, 0), (0, 3)], 4, true).unwrap();
+    let al = AdjList::new(&g, NeighborMode::Out, Loops::Twice, true).unwrap();
+    let h = al.to_graph(NeighborMode::Out, false).unwrap();
+    assert!(h.is_directed());
+    assert_eq!(h.vcount(), 4);
+    let mut edges = h.get_edgelist().unwrap();
+    edges.sort();
+    assert_eq!(edges, vec![(0, 1), (0, 3), (1, 2), (2, 0)]);
+
+    let ring = Graph::ring(5, false, false, true).unwrap();
+    let al = AdjList::new(&ring, NeighborMode::All, Loops::Twice, true).unwrap();
+    let h = al.to_graph(NeighborMode::All, true).unwrap();
+    assert!(!h.is_directed());
+    assert_eq!(h.ecount(), 5);
+}
+
+#[test]
+fn test_inclist() {
+    let g = Graph::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)], 4, false).unwrap();
+    let il = IncList::new(&g, NeighborMode::All, Loops::Twice).unwrap();
+    let mut incident = il[2].to_vec();
+    incident.sort();
+    assert_eq!(incident, vec![1, 2, 3]);
+    assert_eq!(&il[3], &[3]);
+
+    let mut from_il = AdjList::from_inclist(&il).unwrap();
+    let mut direct = AdjList::new(&g, NeighborMode::All, Loops::Twice, true).unwrap();
+    from_il.sort();
+    direct.sort();
+    assert!(from_il.iter().eq(direct.iter()));
+}
+
+#[test]
+fn test_adjlist_from_directed_inclist() {
+    let g = Graph::from_edges(&[(0, 1), (0, 2), (2, 1), (1, 1)], 3, true).unwrap();
+    let il = IncList::new(&g, NeighborMode::Out, Loops::Once).unwrap();
+    let mut al = AdjList::from_inclist(&il).unwrap();
+    al.sort();
+    assert_eq!(&al[0], &[1, 2]);
+    assert_eq!(&al[1], &[1]);
+    assert_eq!(&al[2], &[1]);
+}
+
+#[test]
+fn test_lazy_lists() {
+    let g = Graph::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)], 4, true).unwrap();
+    let al = LazyAdjList::new(&g, NeighborMode::Out, Loops::Twice, true).unwrap();
+    let first = al.get(2).unwrap();
+    let mut out = first.to_vec();
+    out.sort();
+    assert_eq!(out, vec![0, 3]);
+    // A second lookup reuses the cached list while the first is still borrowed.
+    assert_eq!(al.get(2).unwrap(), first);
+    assert_eq!(al.get(0).unwrap(), &[1]);
+    assert!(matches!(al.get(4), Err(Error::InvalidVertexId)));
+
+    let il = LazyIncList::new(&g, NeighborMode::In, Loops::Twice).unwrap();
+    assert_eq!(il.get(0).unwrap(), &[2]);
+    assert_eq!(il.get(3).unwrap(), &[3]);
+    assert_eq!(il.len(), 4);
+}
+
+// === Matrix result tests ===
+
+#[test]
+fn test_matrix_rows_and_columns() {
+    let mut m = Matrix::from_rows(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]).unwrap();
+    assert_eq!((m.nrow(), m.ncol()), (2, 3));
+    assert_eq!(m[(1, 0)], 4.0);
+    assert_eq!(m.column(2), &[3.0, 6.0]);
+    assert_eq!(m.row(1).collect::<Vec<_>>(), vec![4.0, 5.0, 6.0]);
+    assert_eq!(m.to_row_major(), vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    assert_eq!(m.as_slice(), &[1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+    m[(0, 1)] = 7.0;
+    assert_eq!(m.get(0, 1), 7.0);
+    assert_eq!(m.columns().len(), 3);
+    assert_eq!(
+        m,
+        Matrix::from_row_major(2, 3, &[1.0, 7.0, 3.0, 4.0, 5.0, 6.0]).unwrap()
+    );
+    assert!(matches!(
+        Matrix::from_rows(&[vec![1.0], vec![2.0, 3.0]]),
+        Err(Error::InvalidValue)
+    ));
+    assert!(matches!(
+        Matrix::from_row_major(1 << 32, 1 << 32, &[]),
+        Err(Error::InvalidValue)
+    ));
+}
+
+#[test]
+fn test_matrix_transpose() {
+    let mut m = Matrix::from_rows(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]).unwrap();
+    m.transpose().unwrap();
+    assert_eq!((m.nrow(), m.ncol()), (3, 2));
+    assert_eq!(
+        m.to_vec_of_vecs(),
+        vec![vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]]
+    );
+}
+
+#[test]
+fn test_distances_matrix_selection() {
+    let g = Graph::ring(5, false, false, false).unwrap();
+    let dist = g
+        .distances(
+            VertexSelector::List(&[0, 4]),
+            VertexSelector::All,
+            NeighborMode::All,
+        )
+        .unwrap();
+    assert_eq!((dist.nrow(), dist.ncol()), (2, 5));
+    assert_eq!(
+        dist.row(0).collect::<Vec<_>>(),
+        vec![0.0, 1.0, 2.0, 3.0, 4.0]
+    );
+    assert_eq!(dist[(1, 0)], 4.0);
+}
+
+#[test]
+fn test_adjacency_and_laplacian() {
+    let g = Graph::from_edges(&[(0, 1), (1, 2)], 3, false).unwrap();
+    let adj = g
+        .get_adjacency(AdjacencyType::Both, None, Loops::Twice)
+        .unwrap();
+    assert_eq!(
+        adj.to_vec_of_vecs(),
+        vec![
+            vec![0.0, 1.0, 0.0],
+            vec![1.0, 0.0, 1.0],
+            vec![0.0, 1.0, 0.0]
+        ]
+    );
+    let upper = g
+        .get_adjacency(AdjacencyType::Upper, Some(&[2.0, 3.0]), Loops::Twice)
+        .unwrap();
+    assert_eq!(upper[(0, 1)], 2.0);
+    assert_eq!(upper[(1, 0)], 0.0);
+
+    let lap = g
+        .laplacian(
+            NeighborMode::All,
+            LaplacianNormalization::Unnormalized,
+            None,
+        )
+        .unwrap();
+    assert_eq!(
+        lap.to_vec_of_vecs(),
+        vec![
+            vec![1.0, -1.0, 0.0],
+            vec![-1.0, 2.0, -1.0],
+            vec![0.0, -1.0, 1.0]
+        ]
+    );
+}
+
+#[test]
+fn test_layouts() {
+    let g = Graph::ring(6, false, false, true).unwrap();
+    let circle = g.layout_circle(VertexSelector::All).unwrap();
+    assert_eq!((circle.nrow(), circle.ncol()), (6, 2));
+    for r in 0..6 {
+        let (x, y) = (circle.get(r, 0), circle.get(r, 1));
+        assert!((x.hypot(y) - 1.0).abs() < 1e-9);
+    }
+
+    let star = g.layout_star(2, None).unwrap();
+    assert_eq!(star.row(2).collect::<Vec<_>>(), vec![0.0, 0.0]);
+    assert!(matches!(
+        g.layout_star(6, None),
+        Err(Error::InvalidVertexId)
+    ));
+
+    let grid = g.layout_grid(Some(3)).unwrap();
+    assert_eq!(grid.row(4).collect::<Vec<_>>(), vec![1.0, 1.0]);
+    assert_eq!(g.layout_sphere().unwrap().ncol(), 3);
+
+    let a = g.layout_fruchterman_reingold(100, None, Some(7)).unwrap();
+    let b = g.layout_fruchterman_reingold(100, None, Some(7)).unwrap();
+    assert_eq!(a, b);
+    assert!(a.iter().all(|c| c.is_finite()));
+    assert!(
+        g.layout_random(Some(1))
+            .unwrap()
+            .iter()
+            .all(|c| c.abs() <= 1.0)
+    );
+
+    let tree = Graph::from_edges(&[(0, 1), (0, 2), (1, 3)], 4, false).unwrap();
+    let rt = tree
+        .layout_reingold_tilford(NeighborMode::All, Some(&[0]))
+        .unwrap();
+    assert_eq!(rt.column(1), &[0.0, 1.0, 1.0, 2.0]);
+}
+
+// === ndarray and nalgebra interop tests ===
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn test_ndarray_matrix_roundtrip() {
+    let mut m = Matrix::from_rows(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]).unwrap();
+    let view = m.ndarray_view();
+    assert_eq!(view.dim(), (2, 3));
+    assert_eq!(view[[1, 2]], 6.0);
+    let owned = m.to_ndarray();
+    assert_eq!(owned, ndarray::array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    assert_eq!(Matrix::from_ndarray(&owned).unwrap(), m);
+    assert_eq!(
+        Matrix::from_ndarray(&owned.t()).unwrap().to_vec_of_vecs(),
+        vec![vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]]
+    );
+    m.ndarray_view_mut()[[0, 0]] = 9.0;
+    assert_eq!(m[(0, 0)], 9.0);
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn test_ndarray_vectors_and_results() {
+    let v = Vector::from_slice(&[1.0, 2.0]).unwrap();
+    assert_eq!(v.ndarray_view(), ndarray::arr1(&[1.0, 2.0]));
+    let strided = ndarray::arr1(&[1i64, 2, 3, 4]);
+    let vi = VectorInt::from_ndarray(&strided.slice(ndarray::s![..;2])).unwrap();
+    assert_eq!(vi.to_vec(), vec![1, 3]);
+
+    let g = Graph::from_edges(&[(0, 1), (1, 2)], 3, false).unwrap();
+    let dist = g
+        .distances(VertexSelector::All, VertexSelector::All, NeighborMode::All)
+        .unwrap()
+        .to_ndarray();
+    assert_eq!(dist[[0, 2]], 2.0);
+    let lap: ndarray::Array2<f64> = g
+        .laplacian(
+            NeighborMode::All,
+            LaplacianNormalization::Unnormalized,
+            None,
+        )
+        .unwrap()
+        .into();
+    assert_eq!(lap.sum(), 0.0);
+    let layout = g.layout_circle(VertexSelector::All).unwrap();
+    assert_eq!(ndarray::Array2::from(&layout).dim(), (3, 2));
+    let degrees: ndarray::Array1<i64> = VectorInt::from_slice(&[1, 2, 1]).unwrap().into();
+    assert_eq!(degrees.sum(), 4);
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn test_nalgebra_roundtrip() {
+    let mut m = Matrix::from_rows(&[[1.0, 2.0], [3.0, 4.0]]).unwrap();
+    assert_eq!(m.dmatrix_view()[(1, 0)], 3.0);
+    let dm = m.to_dmatrix();
+    assert_eq!(dm, nalgebra::dmatrix![1.0, 2.0; 3.0, 4.0]);
+    assert_eq!(Matrix::from_dmatrix(&dm).unwrap(), m);
+    m.dmatrix_view_mut()[(0, 1)] = 7.0;
+    assert_eq!(m[(0, 1)], 7.0);
+
+    let v = VectorInt::from_slice(&[5, 6]).unwrap();
+    assert_eq!(v.to_dvector(), nalgebra::dvector![5, 6]);
+    assert_eq!(
+        Vector::from_dvector(&nalgebra::dvector![0.5])
+            .unwrap()
+            .to_vec(),
+        vec![0.5]
+    );
+
+    let g = Graph::ring(4, false, false, true).unwrap();
+    let adj: nalgebra::DMatrix<f64> = g
+        .get_adjacency(AdjacencyType::Both, None, Loops::Twice)
+        .unwrap()
+        .into();
+    assert_eq!(adj, adj.transpose());
+    assert_eq!(adj.row_sum()[0], 2.0);
+}